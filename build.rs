@@ -0,0 +1,20 @@
+fn main() {
+    let minor = rustc_minor_version().unwrap_or(0);
+    // Lest newer toolchains warn about the following `cfg`.
+    if minor >= 80 {
+        println!("cargo:rustc-check-cfg=cfg(diagnostic_namespace)");
+    }
+    // `#[diagnostic::on_unimplemented]` is only available starting with 1.78.0.
+    if minor >= 78 {
+        println!("cargo:rustc-cfg=diagnostic_namespace");
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+}
+
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = ::std::env::var_os("RUSTC")?;
+    let output = ::std::process::Command::new(rustc).arg("--version").output().ok()?;
+    // e.g., `rustc 1.65.0 (897e37553 2022-11-02)`
+    let version = ::core::str::from_utf8(&output.stdout).ok()?;
+    version.split('.').nth(1)?.parse().ok()
+}
@@ -17,6 +17,9 @@
 #[macro_use]
 extern crate macro_rules_attribute;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 /// The crate's prelude.
 pub
 mod prelude {
@@ -24,7 +27,12 @@ mod prelude {
     pub use crate::{
         ForLt,
         ForLifetime,
+        HasForLt,
+        Hkt,
+        hkt,
         extra_arities::*,
+        lending_iterator::LendingIterator,
+        lending_stream::LendingStream,
     };
 }
 
@@ -33,15 +41,26 @@ mod prelude {
 mod ඞ {
     pub use {
         ::core, // or `std`
+        ::paste::paste,
         crate::{
             extra_arities::{
                 for_lt_and_lt::WithLifetimes,
+                for_lt_and_lt_and_lt,
+                for_lt_and_lt_and_lt_and_lt,
+            },
+            seal::{
+                Nominal,
             },
             with_lifetime::{
                 WithLifetime,
             },
         },
     };
+    #[cfg(feature = "proc-macros")]
+    pub use {
+        ::higher_kinded_types_proc_macros::For_munch,
+    };
+
     #[cfg(feature = "fn_traits")]
     pub use {
         crate::{
@@ -52,6 +71,17 @@ mod ඞ {
         },
     };
 
+    /// What a `ForLt!` shorthand type without any `'_` (nor elided `&`)
+    /// lifetime, such as `ForLt!(u8)`, goes through, so as to warn about it.
+    #[deprecated(note = "\
+        no `'_` (nor elided `&`) lifetime in this `ForLt!` shorthand type: \
+        if it has a hidden elided lifetime, such as `Cow<str>`, make it \
+        explicit (`Cow<'_, str>`); else, for a type which does not depend on \
+        the lifetime, use `ForFixed<…>` instead\
+    ")]
+    pub
+    type ForLtWithoutLifetime<T> = T;
+
     /// Do not use this type!
     pub
     struct ForLt<T : ?Sized>(
@@ -71,7 +101,11 @@ mod ඞ {
 
 use {
     crate::{
-        with_lifetime::WithLifetime,
+        utils::macro_export,
+        with_lifetime::{
+            WithLifetime,
+            WithSizedLifetime,
+        },
     },
 };
 
@@ -84,6 +118,9 @@ mod extra_arities;
 #[cfg(feature = "fn_traits")]
 mod fn_traits;
 
+pub
+mod for_lt_where;
+
 #[allow(unused_imports)]
 #[doc(hidden)]
 pub use hkt_macro::*;
@@ -91,23 +128,63 @@ mod hkt_macro;
 
 mod hkt_muncher;
 
+pub
+mod lending_iterator;
+
+pub
+mod lending_stream;
+
 #[cfg_attr(feature = "docs-rs",
     doc(cfg(advanced)),
 )]
 pub
 mod type_eq;
 
+pub use type_name::hkt_type_name;
+pub
+mod type_name;
+
 mod utils;
 
+#[cfg_attr(feature = "docs-rs",
+    doc(cfg(advanced)),
+)]
+pub
+mod variance;
+
 mod with_lifetime {
     pub
     trait WithLifetime<'lt>
     :
         Send + Sync + Unpin
     {
+        type T : ?Sized;
+
+        /// The name recorded by `new_For_type!`, for `crate::type_name`.
+        fn ඞtype_name() -> Option<&'static str>
+        where
+            Self : Sized,
+        {
+            None
+        }
+    }
+
+    /// The `Sized` ones, which are the ones for which `ForLt` is implemented.
+    pub
+    trait WithSizedLifetime<'lt> : WithLifetime<'lt> {
         type T;
     }
 
+    impl<'lt, T : ?Sized + WithLifetime<'lt>>
+        WithSizedLifetime<'lt>
+    for
+        T
+    where
+        <T as WithLifetime<'lt>>::T : Sized,
+    {
+        type T = <T as WithLifetime<'lt>>::T;
+    }
+
     impl<'lt, T : ?Sized + WithLifetime<'lt>>
         WithLifetime<'lt>
     for
@@ -178,9 +255,12 @@ mod with_lifetime {
 ///         or <code>[ForLt!]\(Cow\<\'_, str\>\)</code> for more complex
 ///         lifetime-infected types;
 ///
-///       - <code>[ForLt!]\(u8\)</code> or other owned types work too: it is not
-///         mandatory, at the call-site, to be lifetime-infected, it is just
-///         _possible_ (maximally flexible API). See [`ForFixed`].
+///       - <code>[ForFixed]\<u8\></code> or other owned types work too: it is
+///         not mandatory, at the call-site, to be lifetime-infected, it is just
+///         _possible_ (maximally flexible API). Note that the shorthand
+///         <code>[ForLt!]\(u8\)</code> works too, but is deprecated (it
+///         warns), since a type without `'_` is more often than not a typo,
+///         such as `Cow<str>` for `Cow<'_, str>`.
 ///
 ///  1. #### Callee/API author
 ///
@@ -226,7 +306,7 @@ mod with_lifetime {
 ///          <details class="custom"><summary><span class="summary-box"><span>Click to show</span></span></summary>
 ///
 ///          ```rust
-///          use ::higher_kinded_types::ForLt;
+///          use ::higher_kinded_types::{ForFixed, ForLt};
 ///
 ///          fn slice_sort_by_key<Item, Key : ForLt> (
 ///              items: &'_ mut [Item],
@@ -248,7 +328,7 @@ mod with_lifetime {
 ///          slice_sort_by_key::<_, ForLt!(&str)>(clients, |c| &c.key); // ✅
 ///
 ///          // Important: owned case works too!
-///          slice_sort_by_key::<_, ForLt!(u8)>(clients, |c| c.version); // ✅
+///          slice_sort_by_key::<_, ForFixed<u8>>(clients, |c| c.version); // ✅
 ///
 ///          # #[cfg(any())] {
 ///          // But the classic `sort_by_key` stdlib API fails, since it does not use HKTs:
@@ -453,6 +533,13 @@ mod with_lifetime {
 /// is, itself, lifetime-generic, is `A`.
 ///
 /// This is where [`ForLt!`] and HKTs, thus, shine.
+#[cfg_attr(diagnostic_namespace, diagnostic::on_unimplemented(
+    message = "`{Self}` is not a `ForLt` type",
+    label = "expected a `ForLt!(…)` type",
+    note = "use `ForLt!(…)` to produce one: e.g., `ForLt!(&str)` rather than `&str`, \
+            or `ForLt!(Cow<'_, str>)`",
+    note = "for a type which does not depend on the lifetime, use `ForFixed<…>`",
+))]
 pub
 trait ForLifetime : seal::Sealed
 // where
@@ -465,6 +552,13 @@ trait ForLifetime : seal::Sealed
     ///
     /// [ForLt]: trait@ForLt
     type Of<'lt>;
+
+    /// Not part of the public API.
+    #[doc(hidden)]
+    fn ඞtype_name() -> Option<&'static str>
+    where
+        Self : Sized,
+    ;
 }
 
 /// Shorthand alias.
@@ -472,19 +566,42 @@ trait ForLifetime : seal::Sealed
 pub use ForLifetime as ForLt;
 
 mod seal {
+    #[cfg_attr(diagnostic_namespace, diagnostic::on_unimplemented(
+        message = "`{Self}` is neither a `ForLt!(…)` nor a `new_For_type!` type",
+        note = "`ForLt` cannot be implemented manually: use `ForLt!(…)`, \
+                or a `new_For_type! {{ type Name = For!(#![nominal] <'r> = …); }}`",
+    ))]
     pub trait Sealed : Send + Sync + Unpin {}
+    // That is, `crate::ඞ::ForLt<…>`, and the nominal types, such as
+    // `For!(#![nominal] <'r> = …)`'s: the sole implementors of the (hidden)
+    // `WithLifetime` (no need for an extra `Nominal` bound, which would
+    // otherwise show up in the diagnostics).
     #[cfg(not(feature = "better-docs"))]
-    impl<T : ?Sized> Sealed for crate::ඞ::ForLt<T> {}
+    impl<T : ?Sized> Sealed for T
+    where
+        Self : for<'any> crate::WithLifetime<'any>,
+    {}
     #[cfg(feature = "better-docs")]
     impl<T : ?Sized> Sealed for T where Self : Send + Sync + Unpin {}
+
+    /// Marker for the nominal types produced by this crate's macros (such as
+    /// [`new_For_type!`][crate::extra_arities::new_For_type]).
+    pub trait Nominal {}
 }
 
 #[doc(hidden)]
 impl<T : ?Sized> ForLt for T
 where
-    Self : for<'any> WithLifetime<'any> + seal::Sealed,
+    Self : for<'any> WithSizedLifetime<'any> + seal::Sealed,
 {
-    type Of<'lt> = <Self as WithLifetime<'lt>>::T;
+    type Of<'lt> = <Self as WithSizedLifetime<'lt>>::T;
+
+    fn ඞtype_name() -> Option<&'static str>
+    where
+        Self : Sized,
+    {
+        <Self as WithLifetime<'static>>::ඞtype_name()
+    }
 }
 
 crate::utils::cfg_match! {
@@ -537,7 +654,7 @@ crate::utils::cfg_match! {
 /// To be used when the generic lifetime parameter is to be ignored, while
 /// calling into some HKT API.
 pub
-type ForFixed<T : Sized> = ForLt!(T);
+type ForFixed<T : Sized> = ForLt!(<'_unused> = T);
 
 /// <code>[ForRef]\<T\></code> is a macro-free alias for
 /// <code>[ForLt!]\(\<\'any\> = \&\'any T\)</code>.
@@ -546,9 +663,428 @@ type ForRef<T : ?Sized> = ForLt!(&'_ T);
 
 /// <code>[ForRefMut]\<T\></code> is a macro-free alias for
 /// <code>[ForLt!]\(\<\'any\> = \&\'any mut T\)</code>.
+///
+/// It is, for instance, the `Item` of the [`windows_mut()`] lending iterator.
 pub
 type ForRefMut<T : ?Sized> = ForLt!(&'_ mut T);
 
+/// Overlapping `&mut` windows of length `size` over `slice`, as a
+/// [`LendingIterator`][lending_iterator::LendingIterator] (which an
+/// [`Iterator`] cannot be, since its items would overlap).
+///
+/// The mutable counterpart of [`<[T]>::windows()`][slice::windows].
+///
+/// ## Panics
+///
+/// If `size` is `0`.
+///
+/// ## Example
+///
+/// ```rust
+/// use ::higher_kinded_types::{lending_iterator::LendingIterator, windows_mut};
+///
+/// let mut fib = [1, 1, 0, 0, 0, 0];
+/// windows_mut(&mut fib, 3).for_each(|w| {
+///     w[2] = w[0] + w[1];
+/// });
+/// assert_eq!(fib, [1, 1, 2, 3, 5, 8]);
+/// ```
+pub
+fn windows_mut<T>(slice: &'_ mut [T], size: usize)
+  -> WindowsMut<'_, T>
+{
+    assert!(size != 0, "window size must be non-zero");
+    WindowsMut { slice, size, start: 0 }
+}
+
+/// The [`LendingIterator`][lending_iterator::LendingIterator] returned by
+/// [`windows_mut()`].
+pub
+struct WindowsMut<'slice, T> {
+    slice: &'slice mut [T],
+    size: usize,
+    start: usize,
+}
+
+impl<T> lending_iterator::LendingIterator for WindowsMut<'_, T> {
+    type Item = ForRefMut<[T]>;
+
+    fn next(&mut self)
+      -> Option<&'_ mut [T]>
+    {
+        let window = self.slice.get_mut(self.start ..)?.get_mut(.. self.size)?;
+        self.start += 1;
+        Some(window)
+    }
+}
+
+/// Pairs of `&mut` references to adjacent elements of `slice`: `(&mut s[0],
+/// &mut s[1])`, then `(&mut s[1], &mut s[2])`, and so on, as a
+/// [`LendingIterator`][lending_iterator::LendingIterator] (since consecutive
+/// pairs overlap).
+///
+/// ## Example
+///
+/// ```rust
+/// use ::higher_kinded_types::{lending_iterator::LendingIterator, pairs_mut};
+///
+/// let mut xs = [3, 1, 2];
+/// // one bubble-sort pass.
+/// pairs_mut(&mut xs).for_each(|(a, b)| {
+///     if a > b {
+///         ::core::mem::swap(a, b);
+///     }
+/// });
+/// assert_eq!(xs, [1, 2, 3]);
+/// ```
+pub
+fn pairs_mut<T>(slice: &'_ mut [T])
+  -> PairsMut<'_, T>
+{
+    PairsMut { slice, start: 0 }
+}
+
+/// The [`LendingIterator`][lending_iterator::LendingIterator] returned by
+/// [`pairs_mut()`].
+pub
+struct PairsMut<'slice, T> {
+    slice: &'slice mut [T],
+    start: usize,
+}
+
+impl<T> lending_iterator::LendingIterator for PairsMut<'_, T> {
+    type Item = ForLt!(<'r> = (&'r mut T, &'r mut T));
+
+    fn next(&mut self)
+      -> Option<(&'_ mut T, &'_ mut T)>
+    {
+        match self.slice.get_mut(self.start ..)? {
+            | [a, b, ..] => {
+                self.start += 1;
+                Some((a, b))
+            },
+            | _ => None,
+        }
+    }
+}
+
+/// `&mut` runs of consecutive elements of `slice` for which
+/// `same_chunk(&a, &b)` holds.
+///
+/// The mutable counterpart of `<[T]>::chunk_by()`. Since these runs do not
+/// overlap, this is a plain [`Iterator`], with `&'slice mut [T]` items.
+///
+/// ## Example
+///
+/// ```rust
+/// use ::higher_kinded_types::chunk_by_mut;
+///
+/// let mut xs = [1, 1, 2, 3, 3, 3];
+/// for run in chunk_by_mut(&mut xs, |a, b| a == b) {
+///     let len = run.len() as i32;
+///     run.iter_mut().for_each(|x| *x *= len);
+/// }
+/// assert_eq!(xs, [2, 2, 2, 9, 9, 9]);
+/// ```
+pub
+fn chunk_by_mut<T, F>(slice: &'_ mut [T], same_chunk: F)
+  -> ChunkByMut<'_, T, F>
+where
+    F : FnMut(&T, &T) -> bool,
+{
+    ChunkByMut { slice, same_chunk }
+}
+
+/// The [`Iterator`] returned by [`chunk_by_mut()`].
+pub
+struct ChunkByMut<'slice, T, F> {
+    slice: &'slice mut [T],
+    same_chunk: F,
+}
+
+impl<'slice, T, F> Iterator for ChunkByMut<'slice, T, F>
+where
+    F : FnMut(&T, &T) -> bool,
+{
+    type Item = &'slice mut [T];
+
+    fn next(&mut self)
+      -> Option<&'slice mut [T]>
+    {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let len =
+            1 + self.slice
+                .windows(2)
+                .take_while(|pair| (self.same_chunk)(&pair[0], &pair[1]))
+                .count()
+        ;
+        let (chunk, rest) = ::core::mem::take(&mut self.slice).split_at_mut(len);
+        self.slice = rest;
+        Some(chunk)
+    }
+}
+
+/// Types which know their own [`ForLt`][ForLt] "family", that is, the
+/// <code>[ForLt!]\(Self\<\'_\>\)</code> type, so that it can be named
+/// from any of its instances: <code>\<Token\<\'static\> as [HasForLt]\>::ForLt</code>.
+///
+/// [ForLt]: trait@ForLt
+/// [HasForLt]: trait@HasForLt
+///
+/// Rather than implementing it manually, it can be derived with the
+/// `proc-macros` Cargo feature, which takes care of the generic parameters
+/// and `where` clauses of the type:
+///
+#[cfg_attr(feature = "proc-macros", doc = "```rust")]
+#[cfg_attr(not(feature = "proc-macros"), doc = "```rust ,ignore")]
+/// use ::higher_kinded_types::{ForLt, HasForLt};
+///
+/// #[derive(HasForLt)]
+/// struct Token<'src, Kind : Copy = u8>
+/// where
+///     Kind : ::core::fmt::Debug,
+/// {
+///     kind: Kind,
+///     text: &'src str,
+/// }
+///
+/// /// Same as `ForLt!(Token<'_, Kind>)`.
+/// type TokenFor<Kind = u8> = <Token<'static, Kind> as HasForLt>::ForLt;
+///
+/// fn first_word<Kind : Copy + ::core::fmt::Debug>(
+///     s: &str,
+///     kind: Kind,
+/// ) -> <TokenFor<Kind> as ForLt>::Of<'_>
+/// {
+///     Token { kind, text: s.split(' ').next().unwrap() }
+/// }
+/// ```
+///
+/// The derive, for a type with two lifetime parameters, is that of
+/// [`HasForLtAndLt`][extra_arities::HasForLtAndLt].
+pub
+trait HasForLt {
+    /// <code>[ForLt!]\(Self\<\'_\>\)</code>.
+    ///
+    /// [ForLt]: trait@ForLt
+    type ForLt : ForLt;
+}
+
+/// <code>#[derive([HasForLt])]</code>: implements [`HasForLt`][trait@HasForLt]
+/// for a type with exactly one lifetime parameter.
+///
+/// [HasForLt]: trait@HasForLt
+///
+/// The generated impl refers to this crate as `::higher_kinded_types`; when it
+/// is only reachable under some other path (_e.g._, through a re-export, or
+/// from within a macro), that path can be given with a
+/// `#[higher_kinded_types(crate = path)]` attribute (which
+/// <code>#[derive([HasForLtAndLt][extra_arities::HasForLtAndLt])]</code>
+/// accepts as well):
+///
+/// ```rust
+/// mod some {
+///     pub mod reexport {
+///         pub use ::higher_kinded_types::{ForLt, HasForLt};
+///     }
+/// }
+///
+/// use some::reexport::HasForLt;
+///
+/// #[derive(HasForLt)]
+/// #[higher_kinded_types(crate = some::reexport)]
+/// struct Token<'src>(&'src str);
+/// ```
+#[cfg(feature = "proc-macros")]
+#[cfg_attr(feature = "docs-rs", doc(cfg(feature = "proc-macros")))]
+pub use ::higher_kinded_types_proc_macros::HasForLt;
+
+/// <code>#[[ForLt_mirror]\(Mirror\)]</code>: bridges a trait with `type Assoc<'lt>`
+/// GATs to a `Mirror` trait with `type Assoc : ForLt` associated types instead.
+///
+/// Given a trait with lifetime GATs, it generates a `Mirror` trait, with the same
+/// generics, supertraits, constants and methods, but wherein each
+/// `type Gat<'lt>` becomes a `type Gat : ForLt`, and each `Self::Gat<'lt>`, a
+/// <code>\<Self::Gat as [ForLt]\>::Of\<\'lt\></code>; alongside blanket impls
+/// in both directions:
+///
+///   - every `impl Trait` is an `impl Mirror`, so that the GAT-based
+///     implementors can be fed to the `ForLt`-based APIs;
+///
+///   - every <code>\<T : Mirror\></code> yields a
+///     <code>[ViaForLt]\<T\> : Trait</code>.
+///
+///     (a `T : Trait` blanket impl would overlap with the implementors of
+///     `Trait`, hence the newtype).
+///
+/// [ForLt]: trait@ForLt
+///
+#[cfg_attr(feature = "proc-macros", doc = "```rust")]
+#[cfg_attr(not(feature = "proc-macros"), doc = "```rust ,ignore")]
+/// use ::higher_kinded_types::{ForLt, ForLt_mirror, ViaForLt};
+///
+/// #[ForLt_mirror(LendingIteratorForLt)]
+/// trait LendingIterator {
+///     type Item<'next> where Self : 'next;
+///
+///     fn next(&mut self) -> Option<Self::Item<'_>>;
+/// }
+///
+/// /// A GAT-based implementor.
+/// struct WindowsMut<'slice, T> {
+///     slice: &'slice mut [T],
+///     start: usize,
+/// }
+///
+/// impl<T> LendingIterator for WindowsMut<'_, T> {
+///     type Item<'next> = &'next mut [T] where Self : 'next;
+///
+///     fn next(&mut self) -> Option<&'_ mut [T]> {
+///         let window = self.slice.get_mut(self.start ..)?.get_mut(.. 2)?;
+///         self.start += 1;
+///         Some(window)
+///     }
+/// }
+///
+/// /// A `ForLt`-based API.
+/// fn count<I : LendingIteratorForLt>(mut iter: I) -> usize {
+///     let mut count = 0;
+///     while let Some(_) = iter.next() {
+///         count += 1;
+///     }
+///     count
+/// }
+///
+/// let mut array = [0, 1, 2, 3];
+/// assert_eq!(count(WindowsMut { slice: &mut array, start: 0 }), 3);
+///
+/// /// A `ForLt`-based implementor.
+/// struct Countdown(u8, String);
+///
+/// impl LendingIteratorForLt for Countdown {
+///     type Item = ForLt!(&str);
+///
+///     fn next(&mut self) -> Option<&'_ str> {
+///         self.0 = self.0.checked_sub(1)?;
+///         self.1 = self.0.to_string();
+///         Some(&self.1)
+///     }
+/// }
+///
+/// let mut countdown = ViaForLt(Countdown(3, String::new()));
+/// let first: Option<&str> = LendingIterator::next(&mut countdown);
+/// assert_eq!(first, Some("2"));
+/// ```
+///
+/// Supported GATs are those with a single lifetime parameter, no bounds, and,
+/// at most, a `where Self : 'lt` clause. For the latter, the methods of the
+/// `impl Mirror` are forwarded through a hidden trait spelling the GATs out
+/// through yet another helper trait (the `ForLt` type itself being
+/// `ForLt!(<'lt> = <Self as MirrorඞGat<'lt>>::T)`), since `<Self as Trait>::Gat<'lt>`
+/// would otherwise require `Self : 'static`.
+#[cfg(feature = "proc-macros")]
+#[cfg_attr(feature = "docs-rs", doc(cfg(feature = "proc-macros")))]
+pub use ::higher_kinded_types_proc_macros::ForLt_mirror;
+
+/// The `impl Trait` of a `T : Mirror`, for some
+/// <code>#\[ForLt_mirror(Mirror)\] trait Trait</code>.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub
+struct ViaForLt<T : ?Sized>(pub T);
+
+/// A value-level token for some <code>T : [ForLt]</code>: a zero-sized, `Copy`
+/// marker which HKT APIs can take as an ordinary argument, so that `T` gets
+/// inferred from it, rather than having to be turbofished.
+///
+/// [ForLt]: trait@ForLt
+///
+/// Use <code>[hkt!]\(…\)</code>, which takes the same input as
+/// <code>[ForLt!]\(…\)</code>, to produce one.
+///
+/// ```rust
+/// use ::higher_kinded_types::{
+///     ForLt, Hkt, hkt,
+///     lending_iterator::{Item, LendingIterator, Map},
+/// };
+///
+/// /// Same as `iter.map::<U, _>(f)`, but with `U` inferred from a token.
+/// fn map_to<I : LendingIterator, U : ForLt, F>(iter: I, _: Hkt<U>, f: F)
+///   -> Map<I, F, U>
+/// where
+///     F : for<'n> FnMut([&'n (); 0], Item<'n, I>) -> U::Of<'n>,
+/// {
+///     iter.map(f)
+/// }
+///
+/// fn first_bytes<I>(iter: I) -> impl LendingIterator<Item = ForLt!(&[u8])>
+/// where
+///     I : LendingIterator<Item = ForLt!(&mut String)>,
+/// {
+///     // Rather than `iter.map::<ForLt!(&[u8]), _>(|[], s| …)`:
+///     map_to(iter, hkt!(&[u8]), |[], s: &mut String| &s.as_bytes()[.. 1])
+/// }
+/// ```
+///
+/// Note that Rust 1.65 (the MSRV) fails to infer `T` from such a token when
+/// the API also has a higher-ranked <code>for\<\'a\> T::Of\<\'a\> : Bound</code>
+/// clause; a turbofish is then still needed with such older compilers, unless
+/// the clause is expressed by means of a [`for_lt_where`] alias, such as
+/// <code>T : [ForLtOrd][for_lt_where::ForLtOrd]</code>.
+pub
+struct Hkt<T : ForLt>(
+    ::core::marker::PhantomData<fn() -> T>,
+);
+
+impl<T : ForLt> Hkt<T> {
+    /// Same as <code>[hkt!]\(…\)</code>, but for an already-named `T`.
+    pub
+    const
+    fn new() -> Self {
+        Self(::core::marker::PhantomData)
+    }
+}
+
+impl<T : ForLt> Clone for Hkt<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T : ForLt> Copy for Hkt<T> {}
+
+impl<T : ForLt> Default for Hkt<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T : ForLt> ::core::fmt::Debug for Hkt<T> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "Hkt<{}>", hkt_type_name::<T>())
+    }
+}
+
+/// <code>[hkt!]\(…\)</code> is the <code>[Hkt]\<[ForLt!]\(…\)\></code> token value.
+///
+/// ```rust
+/// use ::higher_kinded_types::{ForLt, Hkt, hkt};
+///
+/// let _: Hkt<ForLt!(&str)> = hkt!(&str);
+/// let _: Hkt<ForLt!(<'a> = Vec<&'a str>)> = hkt!(<'a> = Vec<&'a str>);
+///
+/// // Its `Debug` output is that of `hkt_type_name()`.
+/// println!("{:?}", hkt!(&str)); // Hkt<&str>
+/// ```
+#[apply(macro_export)]
+macro_rules! hkt {(
+    $($input:tt)*
+) => (
+    $crate::Hkt::<$crate::ForLt!($($input)*)>::new()
+)}
+
 #[cfg(feature = "ui-tests")]
 #[doc = include_str!("compile_fail_tests.md")]
 mod _compile_fail_tests {}
@@ -5,14 +5,109 @@
 //!   - [`ForTy`] so as to be generic over a type parameter,
 //!   - [`ForLtAndLt`] so as to be generic over _two_ lifetime parameters,
 //!   - [`ForLtAndTy`] so as to be generic over a lifetime parameter and a type
-//!     parameter.
+//!     parameter,
+//!   - and so on, up to four parameters (lifetimes first, then types), such as
+//!     [`ForLtAndLtAndLt`] or [`ForTyAndTy`],
+//!   - [`ForConst`] so as to be generic over a `const N: usize` parameter
+//!     (and [`ForLtAndConst`], with an extra lifetime parameter),
+//!   - [`ForLtUnsized`] so as to be generic over a lifetime parameter, but
+//!     with a `?Sized` output, such as `str` or `dyn Trait + '_`,
+//!   - [`ForUnsizedTy`] and [`ForLtAndUnsizedTy`] so as to be generic over a
+//!     `?Sized` type parameter,
+//!   - [`ForTyOrd`] and the other [`for_ty_where`] traits so as to be generic
+//!     over a _bounded_ type parameter.
 //!
 //! [`ForLt`]: trait@ForLt
 
 #[doc(inline)]
 pub use crate::ForLifetime as ForLt;
 
-use crate::utils::macro_export;
+use crate::{
+    utils::macro_export,
+    WithLifetime,
+};
+
+/// A relaxed [`ForLt`], whose `Of<'lt>` is allowed to be `?Sized`.
+///
+/// Indeed, <code>[ForLt]::Of\<\'lt\></code> is, as usual for associated
+/// types, implicitly `Sized`, which rules out naming, say, the
+/// `<'lt> = dyn Display + 'lt` family, so as to then use it behind a
+/// `&T::Of<'lt>` or a `Box<T::Of<'lt>>`. Hence this trait.
+///
+/// The [`ForLt!`][crate::ForLt!] macro produces types which implement it:
+///
+///   - always, even in the `Sized` case, wherein they implement _both_
+///     [`ForLt`] and [`ForLtUnsized`];
+///
+///   - exclusively, in the `?Sized` case: `ForLt!(dyn Display + '_)`,
+///     `ForLt!([&str])`, _etc._
+///
+/// It cannot be manually implemented: the only types implementing this trait
+/// are the ones produced by the [`ForLt!`][crate::ForLt!] macro (but for the
+/// `fn_traits` flavor of it, which only handles `Sized` types), or by
+/// [`new_For_type!`] with a `<'lt> =` family.
+///
+/// [ForLt]: trait@ForLt
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(not(feature = "fn_traits"))] {
+/// use {
+///     ::core::fmt::Display,
+///     ::higher_kinded_types::{
+///         extra_arities::ForLtUnsized,
+///         ForLt,
+///     },
+/// };
+///
+/// /// Lifetime-generic registry of, say, `dyn Display + '_` trait objects.
+/// struct Registry<'r, T : ForLtUnsized> {
+///     entries: Vec<Box<T::Of<'r>>>,
+/// }
+///
+/// impl<'r, T : ForLtUnsized> Registry<'r, T> {
+///     fn first(&self) -> Option<&T::Of<'r>> {
+///         self.entries.first().map(|it| &**it)
+///     }
+/// }
+///
+/// let local = String::from("local");
+/// let registry = Registry::<ForLt!(dyn Display + '_)> {
+///     entries: vec![Box::new(&local), Box::new(42)],
+/// };
+/// assert_eq!(registry.first().unwrap().to_string(), "local");
+///
+/// // `Sized` families work too.
+/// let _: Registry<'_, ForLt!(&str)> = Registry { entries: vec![] };
+/// # }
+/// ```
+pub
+trait ForLtUnsized : crate::seal::Sealed {
+    type Of<'lt> : ?Sized;
+
+    /// Not part of the public API.
+    #[doc(hidden)]
+    fn ඞtype_name() -> Option<&'static str>
+    where
+        Self : Sized,
+    ;
+}
+
+#[doc(hidden)]
+impl<T : ?Sized> ForLtUnsized for T
+where
+    Self : for<'any> WithLifetime<'any> + crate::seal::Sealed,
+{
+    type Of<'lt> = <Self as WithLifetime<'lt>>::T;
+
+    fn ඞtype_name() -> Option<&'static str>
+    where
+        Self : Sized,
+    {
+        <Self as WithLifetime<'static>>::ඞtype_name()
+    }
+}
 
 /// Genericity over a _type_ parameter.
 ///
@@ -33,15 +128,68 @@ use crate::utils::macro_export;
 /// See its documentation for more info.
 ///
 /// [ForLt]: trait@ForLt
+#[cfg_attr(diagnostic_namespace, diagnostic::on_unimplemented(
+    message = "`{Self}` is not a `ForTy` type",
+    label = "expected a `ForTy` type",
+    note = "use `new_For_type!` to produce one: \
+            e.g., `new_For_type! {{ type VecFor = For!(<T> = Vec<T>); }}`",
+))]
 pub
 trait ForTy : Send + Sync + Unpin {
     type Of<T>;
+
+    /// Not part of the public API.
+    #[doc(hidden)]
+    fn ඞtype_name() -> Option<&'static str> {
+        None
+    }
+}
+
+/// A [`ForTy`] which is, furthermore, covariant: `Self::Of<T>` can be shrunk
+/// whenever `T` can.
+///
+/// Such types are produced by the [`new_For_type!`] macro, by marking the type
+/// parameter with `#[covariant]` (or with a leading `#![covariant]`), which
+/// checks the claim at compile time.
+///
+/// Since Rust features no `Sub : Super` bounds, this is expressed (and
+/// checked) as the ability to shrink a reference type parameter, as with
+/// [`CovariantForLtAndTyInT`].
+///
+/// ```rust
+/// use ::higher_kinded_types::extra_arities::*;
+///
+/// new_For_type! {
+///     type VecFor = For!(<#[covariant] T> = Vec<T>);
+/// }
+///
+/// let local = String::from("local");
+/// let mut strs: Vec<&str> = VecFor::covariant_cast(vec!["static"]);
+/// strs.push(&local);
+/// assert_eq!(strs, ["static", "local"]);
+/// ```
+///
+/// ```rust ,compile_fail
+/// use ::higher_kinded_types::extra_arities::*;
+///
+/// new_For_type! {
+///     type NotCov = For!(<#[covariant] T> = ::core::cell::Cell<T>);
+/// }
+/// ```
+pub
+trait CovariantForTy : ForTy {
+    /// Shrinks the lifetime of the `&U` type parameter.
+    fn covariant_cast<'smol, 'humongous : 'smol, U : ?Sized>(
+        it: Self::Of<&'humongous U>,
+    ) -> Self::Of<&'smol U>
+    ;
 }
 
 /// Genericity over _two_ lifetime parameters.
 ///
 /// It cannot be manually implemented: the only types implementing this trait
-/// are the ones produced by the [`For!`] macro.
+/// are the ones produced by the [`For!`] macro (or by [`new_For_type!`], when
+/// variance is involved: see [`CovariantForLtAndLt`]).
 ///
 /// ## Examples
 ///
@@ -70,24 +218,626 @@ trait ForTy : Send + Sync + Unpin {
 ///     // …
 /// }
 /// ```
+#[cfg_attr(diagnostic_namespace, diagnostic::on_unimplemented(
+    message = "`{Self}` is not a `ForLtAndLt` type",
+    label = "expected a `For!(<'a, 'b> = …)` type",
+    note = "use `For!(…)` to produce one: e.g., `For!(&mut Context<'_>)` \
+            rather than `&mut Context<'_>`",
+))]
 pub
 trait ForLtAndLt : for_lt_and_lt::Sealed {
     type Of<'a, 'b>;
+
+    /// Not part of the public API.
+    #[doc(hidden)]
+    fn ඞtype_name() -> Option<&'static str> {
+        None
+    }
+}
+
+/// Same as [`HasForLt`][trait@crate::HasForLt], but for types with two
+/// lifetime parameters, which thus know their own [`ForLtAndLt`] "family":
+/// <code>[For!]\(\<\'a, \'b\> = Self\<\'a, \'b\>\)</code>.
+///
+/// It can be derived with the `proc-macros` Cargo feature:
+///
+#[cfg_attr(feature = "proc-macros", doc = "```rust")]
+#[cfg_attr(not(feature = "proc-macros"), doc = "```rust ,ignore")]
+/// use ::higher_kinded_types::extra_arities::*;
+///
+/// #[derive(HasForLtAndLt)]
+/// struct Parser<'input, 'arena, T> {
+///     input: &'input str,
+///     arena: &'arena mut Vec<T>,
+/// }
+///
+/// type ParserFor<T> = <Parser<'static, 'static, T> as HasForLtAndLt>::ForLtAndLt;
+///
+/// fn parser<'i, 'a, T>(
+///     input: &'i str,
+///     arena: &'a mut Vec<T>,
+/// ) -> <ParserFor<T> as ForLtAndLt>::Of<'i, 'a>
+/// {
+///     Parser { input, arena }
+/// }
+/// ```
+pub
+trait HasForLtAndLt {
+    /// <code>[For!]\(\<\'a, \'b\> = Self\<\'a, \'b\>\)</code>.
+    type ForLtAndLt : ForLtAndLt;
 }
 
+/// <code>#[derive([HasForLtAndLt])]</code>: implements
+/// [`HasForLtAndLt`][trait@HasForLtAndLt] for a type with exactly two lifetime
+/// parameters.
+///
+/// [HasForLtAndLt]: trait@HasForLtAndLt
+#[cfg(feature = "proc-macros")]
+#[cfg_attr(feature = "docs-rs", doc(cfg(feature = "proc-macros")))]
+pub use ::higher_kinded_types_proc_macros::HasForLtAndLt;
+
 /// Genericity over a _lifetime_ and a _type_ parameters.
 ///
 /// Note: the same remarks as for [`ForTy`] apply here: see [`new_For_type!`]'s
 /// documentation for more info and examples about defining and using such
 /// types.
+#[cfg_attr(diagnostic_namespace, diagnostic::on_unimplemented(
+    message = "`{Self}` is not a `ForLtAndTy` type",
+    label = "expected a `ForLtAndTy` type",
+    note = "use `new_For_type!` to produce one: \
+            e.g., `new_For_type! {{ type SharedRef = For!(<'r, T> = &'r T); }}`",
+))]
 pub
 trait ForLtAndTy : Send + Sync + Unpin {
     type Of<'lt, T : 'lt>;
+
+    /// Not part of the public API.
+    #[doc(hidden)]
+    fn ඞtype_name() -> Option<&'static str> {
+        None
+    }
+}
+
+/// A relaxed [`ForTy`], whose type parameter is allowed to be `?Sized`.
+///
+/// Such types are produced by the [`new_For_type!`] macro, by marking the type
+/// parameter as `?Sized`. They then implement [`ForTy`] as well.
+///
+/// ```rust
+/// use ::higher_kinded_types::extra_arities::*;
+///
+/// new_For_type! {
+///     type Boxed = For!(<T : ?Sized> = Box<T>);
+/// }
+///
+/// fn demo<B : ForUnsizedTy>(s: B::Of<str>, xs: B::Of<[u8]>) {}
+///
+/// demo::<Boxed>("str".into(), vec![42].into());
+/// ```
+pub
+trait ForUnsizedTy : Send + Sync + Unpin {
+    type Of<T : ?Sized>;
+
+    /// Not part of the public API.
+    #[doc(hidden)]
+    fn ඞtype_name() -> Option<&'static str> {
+        None
+    }
+}
+
+/// A relaxed [`ForLtAndTy`], whose type parameter is allowed to be `?Sized`.
+///
+/// Such types are produced by the [`new_For_type!`] macro, by marking the type
+/// parameter as `?Sized`. They then implement [`ForLtAndTy`] as well.
+///
+/// ## Example
+///
+/// A `split_at{,_mut}()` which is generic over the `mut`-ness, for `str` and
+/// slices alike:
+///
+/// ```rust
+/// use ::higher_kinded_types::extra_arities::*;
+///
+/// new_For_type! {
+///     type SharedRef = For!(<'r, T : ?Sized> = &'r T);
+///     type ExclusiveRef = For!(<'r, T : ?Sized> = &'r mut T);
+/// }
+///
+/// trait SplitAt<Ref : ForLtAndUnsizedTy> {
+///     fn split_at<'r>(this: Ref::Of<'r, Self>, mid: usize)
+///       -> (Ref::Of<'r, Self>, Ref::Of<'r, Self>)
+///     where
+///         Self : 'r,
+///     ;
+/// }
+///
+/// impl SplitAt<SharedRef> for str {
+///     fn split_at<'r>(this: &'r str, mid: usize) -> (&'r str, &'r str)
+///     where
+///         Self : 'r,
+///     {
+///         this.split_at(mid)
+///     }
+/// }
+///
+/// impl SplitAt<ExclusiveRef> for str {
+///     fn split_at<'r>(this: &'r mut str, mid: usize)
+///       -> (&'r mut str, &'r mut str)
+///     where
+///         Self : 'r,
+///     {
+///         this.split_at_mut(mid)
+///     }
+/// }
+///
+/// impl<T> SplitAt<SharedRef> for [T] {
+///     fn split_at<'r>(this: &'r [T], mid: usize) -> (&'r [T], &'r [T])
+///     where
+///         Self : 'r,
+///     {
+///         this.split_at(mid)
+///     }
+/// }
+///
+/// impl<T> SplitAt<ExclusiveRef> for [T] {
+///     fn split_at<'r>(this: &'r mut [T], mid: usize)
+///       -> (&'r mut [T], &'r mut [T])
+///     where
+///         Self : 'r,
+///     {
+///         this.split_at_mut(mid)
+///     }
+/// }
+///
+/// let (hello, world) = SplitAt::<SharedRef>::split_at("hello, world", 5);
+/// assert_eq!([hello, world], ["hello", ", world"]);
+///
+/// let mut xs = [0, 1, 2, 3];
+/// let (a, b) = SplitAt::<ExclusiveRef>::split_at(&mut xs[..], 2);
+/// ::core::mem::swap(&mut a[0], &mut b[0]);
+/// assert_eq!(xs, [2, 1, 0, 3]);
+///
+/// // And they are still `ForLtAndTy`s:
+/// fn sized<Ref : ForLtAndTy>(_: Ref::Of<'_, i32>) {}
+/// sized::<SharedRef>(&42);
+/// ```
+pub
+trait ForLtAndUnsizedTy : Send + Sync + Unpin {
+    type Of<'lt, T : ?Sized + 'lt>;
+
+    /// Not part of the public API.
+    #[doc(hidden)]
+    fn ඞtype_name() -> Option<&'static str> {
+        None
+    }
+}
+
+/// Genericity over a type parameter _with a bound_, such as `T : Ord`.
+///
+/// Since traits cannot be generic over other traits, there is one such trait
+/// per bound, carrying said bound on its `Of<T>`: [`ForTyOrd`] is the
+/// `<T : Ord>` one, [`ForTyHash`] the `<T : Hash>` one, and so on.
+///
+/// The traits herein cover the bounds of the standard library traits, and are
+/// re-exported from [`extra_arities`][super]. Others, such as a combined
+/// `<T : Ord + Hash>` one, are to be defined with [`new_ForTy_where!`].
+///
+/// Such types are produced by the [`new_For_type!`] macro, by giving a bound to
+/// the type parameter: <code>[For!]\(\<T : Ord\> = …\)</code> implements the
+/// `ForTyOrd` trait in scope, and <code>[For!]\(\<T : Ord + Hash\> = …\)</code>,
+/// the `ForTyOrdHash` one.
+///
+/// [`new_For_type!`]: crate::extra_arities::new_For_type!
+/// [For!]: crate::extra_arities::For!
+///
+/// ## Example
+///
+/// ```rust
+/// use {
+///     ::std::collections::{BTreeSet, BinaryHeap},
+///     ::higher_kinded_types::extra_arities::*,
+/// };
+///
+/// /// A type with a bound of its own.
+/// #[derive(Default)]
+/// struct Sorted<T : Ord>(Vec<T>);
+///
+/// new_For_type! {
+///     type Set = For!(<T : Ord> = BTreeSet<T>);
+///     type Heap = For!(<T : Ord> = BinaryHeap<T>);
+///     type SortedVec = For!(<T : Ord> = Sorted<T>);
+/// }
+///
+/// trait Insert<K> {
+///     fn insert_(&mut self, key: K);
+/// }
+///
+/// impl<K : Ord> Insert<K> for BTreeSet<K> {
+///     fn insert_(&mut self, key: K) {
+///         self.insert(key);
+///     }
+/// }
+///
+/// impl<K : Ord> Insert<K> for BinaryHeap<K> {
+///     fn insert_(&mut self, key: K) {
+///         self.push(key);
+///     }
+/// }
+///
+/// impl<K : Ord> Insert<K> for Sorted<K> {
+///     fn insert_(&mut self, key: K) {
+///         let i = self.0.partition_point(|k| *k < key);
+///         self.0.insert(i, key);
+///     }
+/// }
+///
+/// fn collect<C : ForTyOrd, K : Ord>(
+///     keys: impl IntoIterator<Item = K>,
+/// ) -> C::Of<K>
+/// where
+///     C::Of<K> : Default + Insert<K>,
+/// {
+///     let mut collection = C::Of::<K>::default();
+///     keys.into_iter().for_each(|key| collection.insert_(key));
+///     collection
+/// }
+///
+/// let set = collect::<Set, _>([3, 1, 2, 1]);
+/// assert_eq!(set.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+///
+/// let heap = collect::<Heap, _>([3, 1, 2, 1]);
+/// assert_eq!(heap.into_sorted_vec(), [1, 1, 2, 3]);
+///
+/// let sorted = collect::<SortedVec, _>([3, 1, 2, 1]);
+/// assert_eq!(sorted.0, [1, 1, 2, 3]);
+/// ```
+pub
+mod for_ty_where {
+    use crate::utils::macro_export;
+
+    /// Defines a bounded [`ForTy`][super::ForTy]-like trait:
+    /// <code>trait ForTyBounds = Bounds;</code> stands for
+    /// <code>trait ForTyBounds { type Of\<T : Bounds\>; }</code>.
+    ///
+    /// In order for <code>[For!]\(\<T : Bounds\> = …\)</code> to implement it,
+    /// the trait is to be named `ForTy` followed by the names of the bounds.
+    ///
+    /// [For!]: super::For!
+    ///
+    /// ```rust
+    /// use {
+    ///     ::std::{collections::HashMap, hash::Hash},
+    ///     ::higher_kinded_types::extra_arities::*,
+    /// };
+    ///
+    /// new_ForTy_where! {
+    ///     /// `<T : Ord + Hash>` type families.
+    ///     pub trait ForTyOrdHash = Ord + Hash;
+    /// }
+    ///
+    /// struct Index<K : Ord + Hash>(Vec<K>, HashMap<K, usize>);
+    ///
+    /// new_For_type! {
+    ///     type Indexed = For!(<K : Ord + Hash> = Index<K>);
+    /// }
+    ///
+    /// fn demo<C : ForTyOrdHash>(make: impl FnOnce() -> C::Of<u8>) -> C::Of<u8> {
+    ///     make()
+    /// }
+    ///
+    /// let index = demo::<Indexed>(|| Index(vec![], HashMap::new()));
+    /// assert!(index.0.is_empty() && index.1.is_empty());
+    /// ```
+    #[apply(macro_export)]
+    macro_rules! new_ForTy_where {(
+        $(
+            $(#$attr:tt)*
+            $pub:vis
+            trait $Name:ident =
+                $($Bound:ident)::+ $(+ $($Bounds:ident)::+)*
+            ;
+        )*
+    ) => (
+        $(
+            $(#$attr)*
+            $pub
+            trait $Name : Send + Sync + Unpin {
+                type Of<T : $($Bound)::+ $(+ $($Bounds)::+)*>;
+            }
+        )*
+    )}
+
+    macro_rules! for_ty_where {(
+        $( $Name:ident = $($path:ident)::+ ),* $(,)?
+    ) => (
+        $(
+            new_ForTy_where! {
+                #[doc = ::core::concat!(
+                    "Genericity over a type parameter bounded by `",
+                    ::core::stringify!($($path)::+), "`.",
+                )]
+                pub trait $Name = $($path)::+;
+            }
+        )*
+    )}
+
+    for_ty_where! {
+        ForTyClone = core::clone::Clone,
+        ForTyCopy = core::marker::Copy,
+        ForTyDebug = core::fmt::Debug,
+        ForTyDefault = core::default::Default,
+        ForTyDisplay = core::fmt::Display,
+        ForTyEq = core::cmp::Eq,
+        ForTyHash = core::hash::Hash,
+        ForTyOrd = core::cmp::Ord,
+        ForTyPartialEq = core::cmp::PartialEq,
+        ForTyPartialOrd = core::cmp::PartialOrd,
+        ForTySend = core::marker::Send,
+        ForTySync = core::marker::Sync,
+    }
+}
+#[doc(no_inline)]
+pub use for_ty_where::*;
+
+/// Generates the lifetime-only arities: sealed, and produced by [`For!`], by
+/// means of a `dyn for<…> WithLifetimes<…>`, much like [`ForLtAndLt`] is.
+macro_rules! lifetimes_arities {(
+    $(
+        $(#[$attr:meta])*
+        $Trait:ident in $module:ident [$($lt:lifetime),+];
+    )*
+) => (
+    $(
+        $(#[$attr])*
+        ///
+        /// It cannot be manually implemented: the only types implementing this
+        /// trait are the ones produced by the [`For!`] macro.
+        pub
+        trait $Trait : $module::Sealed {
+            type Of<$($lt),+>;
+
+            /// Not part of the public API.
+            #[doc(hidden)]
+            fn ඞtype_name() -> Option<&'static str> {
+                None
+            }
+        }
+
+        #[doc(hidden)] /** Not part of the public API */ pub
+        mod $module {
+            pub trait Sealed : Send + Sync + Unpin {}
+
+            pub trait WithLifetimes<$($lt),+> {
+                type T;
+            }
+
+            /// Do not use this type!
+            pub
+            struct $Trait<T : ?Sized>(
+                ::core::marker::PhantomData<fn(&()) -> &T>,
+                ::never_say_never::Never,
+            );
+
+            impl<T : ?Sized>
+                Sealed
+            for
+                $Trait<T>
+            where
+                T : for<$($lt),+> WithLifetimes<$($lt),+>,
+            {}
+
+            impl<T : ?Sized>
+                super::$Trait
+            for
+                $Trait<T>
+            where
+                T : for<$($lt),+> WithLifetimes<$($lt),+>,
+            {
+                type Of<$($lt),+> = <T as WithLifetimes<$($lt),+>>::T;
+            }
+        }
+    )*
+)}
+
+lifetimes_arities! {
+    /// Genericity over _three_ lifetime parameters.
+    ///
+    /// ```rust
+    /// use ::higher_kinded_types::extra_arities::*;
+    ///
+    /// struct Ctx<'b, 'c>(&'b mut &'c str);
+    ///
+    /// type RefMutCtx = For!(<'a, 'b, 'c> = &'a mut Ctx<'b, 'c>);
+    ///
+    /// fn set<T : ForLtAndLtAndLt>(_: impl FnOnce(T::Of<'_, '_, 'static>)) {}
+    ///
+    /// set::<RefMutCtx>(|ctx: &mut Ctx<'_, 'static>| *ctx.0 = "static");
+    /// ```
+    ForLtAndLtAndLt in for_lt_and_lt_and_lt ['a, 'b, 'c];
+
+    /// Genericity over _four_ lifetime parameters.
+    ForLtAndLtAndLtAndLt in for_lt_and_lt_and_lt_and_lt ['a, 'b, 'c, 'd];
+}
+
+/// Generates the arities involving types, which are to be implemented by
+/// [`new_For_type!`], much like [`ForTy`] and [`ForLtAndTy`] are.
+///
+/// Every type parameter is expected to outlive every lifetime one.
+macro_rules! types_arities {(
+    $(
+        $(#[$attr:meta])*
+        $Trait:ident [$($generics:tt)*];
+    )*
+) => (
+    $(
+        $(#[$attr])*
+        ///
+        /// Note: the same remarks as for [`ForTy`] apply here: see
+        /// [`new_For_type!`]'s documentation for more info and examples about
+        /// defining and using such types.
+        pub
+        trait $Trait : Send + Sync + Unpin {
+            type Of<$($generics)*>;
+
+            /// Not part of the public API.
+            #[doc(hidden)]
+            fn ඞtype_name() -> Option<&'static str> {
+                None
+            }
+        }
+    )*
+)}
+
+types_arities! {
+    /// Genericity over _two_ type parameters.
+    ///
+    /// ```rust
+    /// use ::higher_kinded_types::extra_arities::*;
+    ///
+    /// new_For_type! {
+    ///     type Result_ = For!(<T, E> = Result<T, E>);
+    ///     type Either = For!(<L, R> = ::core::ops::ControlFlow<L, R>);
+    /// }
+    ///
+    /// fn parse<F : ForTyAndTy>(
+    ///     s: &str,
+    ///     ok: impl FnOnce(i32) -> F::Of<i32, String>,
+    ///     err: impl FnOnce(String) -> F::Of<i32, String>,
+    /// ) -> F::Of<i32, String>
+    /// {
+    ///     match s.parse() {
+    ///         | Ok(n) => ok(n),
+    ///         | Err(e) => err(format!("{e}")),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(parse::<Result_>("42", Ok, Err), Ok(42));
+    /// ```
+    ForTyAndTy[T, U];
+
+    /// Genericity over _three_ type parameters.
+    ForTyAndTyAndTy[T, U, V];
+
+    /// Genericity over _four_ type parameters.
+    ForTyAndTyAndTyAndTy[T, U, V, W];
+
+    /// Genericity over a _lifetime_ and _two_ type parameters.
+    ///
+    /// ```rust
+    /// use {
+    ///     ::std::collections::HashMap,
+    ///     ::higher_kinded_types::extra_arities::*,
+    /// };
+    ///
+    /// new_For_type! {
+    ///     type MapRef = For!(<'r, K, V> = &'r HashMap<K, V>);
+    /// }
+    ///
+    /// fn len<'r, M : ForLtAndTyAndTy>(
+    ///     map: M::Of<'r, &'static str, i32>,
+    ///     len: impl FnOnce(M::Of<'r, &'static str, i32>) -> usize,
+    /// ) -> usize
+    /// {
+    ///     len(map)
+    /// }
+    ///
+    /// let map = HashMap::from([("a", 1)]);
+    /// assert_eq!(len::<MapRef>(&map, |m| m.len()), 1);
+    /// ```
+    ForLtAndTyAndTy['lt, T : 'lt, U : 'lt];
+
+    /// Genericity over a _lifetime_ and _three_ type parameters.
+    ForLtAndTyAndTyAndTy['lt, T : 'lt, U : 'lt, V : 'lt];
+
+    /// Genericity over _two_ lifetimes and a _type_ parameters.
+    ///
+    /// `Of` has to be well-formed for any choice of lifetimes: _e.g._,
+    /// `(&'a T, &'b T)` is fine, but `&'a &'b T` is not (contrary to the
+    /// lifetime-only arities, which are produced by [`For!`] itself).
+    ForLtAndLtAndTy['a, 'b, T : 'a + 'b];
+
+    /// Genericity over _two_ lifetimes and _two_ type parameters.
+    ///
+    /// `Of` has to be well-formed for any choice of lifetimes: _e.g._,
+    /// `(&'a T, &'b T)` is fine, but `&'a &'b T` is not (contrary to the
+    /// lifetime-only arities, which are produced by [`For!`] itself).
+    ForLtAndLtAndTyAndTy['a, 'b, T : 'a + 'b, U : 'a + 'b];
+
+    /// Genericity over _three_ lifetimes and a _type_ parameters.
+    ///
+    /// `Of` has to be well-formed for any choice of lifetimes: _e.g._,
+    /// `(&'a T, &'b T)` is fine, but `&'a &'b T` is not (contrary to the
+    /// lifetime-only arities, which are produced by [`For!`] itself).
+    ForLtAndLtAndLtAndTy['a, 'b, 'c, T : 'a + 'b + 'c];
+
+    /// Genericity over a _`const N: usize`_ parameter.
+    ///
+    /// ```rust
+    /// use ::higher_kinded_types::extra_arities::*;
+    ///
+    /// new_For_type! {
+    ///     type Array = For!(<const N: usize> = [u8; N]);
+    ///     type BoxedArray = For!(<const N: usize> = Box<[u8; N]>);
+    /// }
+    ///
+    /// /// Buffer-size-polymorphic API.
+    /// fn total_len<Buf : ForConst>(
+    ///     small: Buf::Of<64>,
+    ///     large: Buf::Of<4096>,
+    ///     len: impl Fn(&dyn AsRef<[u8]>) -> usize,
+    /// ) -> usize
+    /// where
+    ///     Buf::Of<64> : AsRef<[u8]>,
+    ///     Buf::Of<4096> : AsRef<[u8]>,
+    /// {
+    ///     len(&small) + len(&large)
+    /// }
+    ///
+    /// let len = |buf: &dyn AsRef<[u8]>| buf.as_ref().len();
+    /// assert_eq!(total_len::<Array>([0; 64], [0; 4096], len), 64 + 4096);
+    /// ```
+    ForConst[const N : usize];
+
+    /// Genericity over a _lifetime_ and a _`const N: usize`_ parameters.
+    ///
+    /// ```rust
+    /// use ::higher_kinded_types::extra_arities::*;
+    ///
+    /// new_For_type! {
+    ///     type ArrayRef = For!(<'r, const N: usize> = &'r [u8; N]);
+    ///     type ArrayMut = For!(<'r, const N: usize> = &'r mut [u8; N]);
+    /// }
+    ///
+    /// fn header<'r, Ref : ForLtAndConst>(
+    ///     buf: Ref::Of<'r, 4096>,
+    ///     split: impl FnOnce(Ref::Of<'r, 4096>) -> Ref::Of<'r, 8>,
+    /// ) -> Ref::Of<'r, 8>
+    /// {
+    ///     split(buf)
+    /// }
+    ///
+    /// let mut buf = [0; 4096];
+    /// let header = header::<ArrayMut>(&mut buf, |buf| {
+    ///     (&mut buf[.. 8]).try_into().unwrap()
+    /// });
+    /// header[0] = 42;
+    /// assert_eq!(buf[0], 42);
+    /// ```
+    ForLtAndConst['lt, const N : usize];
 }
 
 /// Same as [`crate::ForLifetime`], but for enforcing covariance of
 /// `Self::Of<'_>` (over `'_`).
 ///
+/// Such types are produced by the [`new_For_type!`] macro (see below), whose
+/// `covariant_cast` is a mere `it`, checked by the compiler against the
+/// concrete type. Hence the lack of an anonymous
+/// <code>[ForLt!]\(#!\[covariant\] …\)</code> counterpart: it would have no
+/// nominal type to carry such a checked impl, and the proof of covariance
+/// would thus be forgeable.
+///
+/// [ForLt!]: crate::ForLt!
+///
 /// ## Example
 ///
 /// ```rust
@@ -151,6 +901,12 @@ trait ForLtAndTy : Send + Sync + Unpin {
 ///   = note: this error originates in the macro `$crate::ඞFor` which comes from the expansion of the macro `new_For_type` (in Nightly builds, run with -Z macro-backtrace for more info)
 /// # */
 /// ```
+#[cfg_attr(diagnostic_namespace, diagnostic::on_unimplemented(
+    message = "`{Self}` is not a `CovariantForLt` type",
+    label = "expected a covariant `new_For_type!` type",
+    note = "use `new_For_type!` to produce one: \
+            e.g., `new_For_type! {{ type StrRef = For!(#![covariant] <'r> = &'r str); }}`",
+))]
 pub
 trait CovariantForLt {
     /// In order to help palliate WF-bounds, this trait carries such a bound.
@@ -167,41 +923,319 @@ trait CovariantForLt {
     ;
 }
 
-/// Variadic version of [`crate::ForLt!`], suitable for the [`For…` traits of
-/// this module][self#traits].
+/// A [`crate::ForLifetime`] which is, furthermore, contravariant: `Self::Of<'_>`
+/// can be _extended_ (over `'_`).
 ///
-/// ### Syntax
+/// This is the case of types such as `fn(&'_ str)`: a callback able to handle
+/// any `&'short str` can be used as one handling `&'static str`s only.
 ///
-///   - #### `ForLt`
+/// Such types are produced by the [`new_For_type!`] macro, with the
+/// `#![contravariant]` annotation, which checks the claim the same way
+/// `#![covariant]` does for [`CovariantForLt`].
 ///
-///     ```rust
-///     # use ::higher_kinded_types::extra_arities::For;
-///     # mod some { pub use ::std::borrow::Cow as Arbitrary; }
-///     # use str as Type; let _:
-///     For!(<'r> = some::Arbitrary<'r, Type>)
-///     # ;
-///     ```
+/// ## Example
 ///
-///       - Notice how there deliberately is no _shorthand syntax_ available
-///         (for the sake of readability). Use [`crate::ForLt!`] for that.
+/// ```rust
+/// use ::higher_kinded_types::extra_arities::*;
 ///
-///   - #### `ForLtAndLt`
+/// new_For_type! {
+///     type StrCallback = For!(#![contravariant] <'r> = fn(&'r str) -> usize);
+/// }
 ///
-///     ```rust
-///     # use ::higher_kinded_types::extra_arities::For;
-///     # mod some { pub type Arbitrary<'a, 'b, T> = &'a &'b T; }
-///     # use str as Type; let _:
-///     For!(<'a, 'b> = some::Arbitrary<'a, 'b, Type>)
-///     # ;
-///     ```
+/// fn call_with_static<'any, T : ContravariantForLt>(
+///     callback: T::Of<'any>,
+///     call: impl FnOnce(T::Of<'static>) -> usize,
+/// ) -> usize
+/// {
+///     call(T::contravariant_cast(callback))
+/// }
 ///
-///   - #### `ForTy`
+/// let len = call_with_static::<StrCallback>(str::len, |f| f("static"));
+/// assert_eq!(len, 6);
+/// ```
+///
+/// ### Counter-example
+///
+/// ```rust ,compile_fail
+/// use ::higher_kinded_types::extra_arities::*;
+///
+/// new_For_type! {
+///     type NotContra = For!(#![contravariant] <'r> = &'r str);
+/// }
+/// ```
+pub
+trait ContravariantForLt : ForLt {
+    /// The actual "proof" which higher-kinded callees dealing with implementors
+    /// of this trait can use in order to take advantage of variance.
+    fn contravariant_cast<'smol, 'humongous : 'smol>(
+        it: Self::Of<'smol>,
+    ) -> Self::Of<'humongous>
+    ;
+}
+
+/// An explicit opt-in marker for [`crate::ForLifetime`]s which are meant to be
+/// invariant: neither [`CovariantForLt`] nor [`ContravariantForLt`].
+///
+/// APIs relying on lifetime branding, for instance, can require this bound in
+/// order to refuse the [`ForRef`][crate::ForRef]-like types with which the
+/// brand could be shrunk away.
+///
+/// Such types are produced by the [`new_For_type!`] macro, with the
+/// `#![invariant]` annotation.
+///
+/// Note that, contrary to the variance ones, this annotation is a mere
+/// declaration, which the compiler cannot check (no such thing as a
+/// `!Covariant` proof). `unsafe` code should thus not rely on it alone.
+///
+/// ## Example
+///
+/// ```rust
+/// use {
+///     ::core::cell::Cell,
+///     ::higher_kinded_types::extra_arities::*,
+/// };
+///
+/// new_For_type! {
+///     type Brand = For!(#![invariant] <'brand> = Cell<&'brand ()>);
+/// }
+///
+/// fn branded_api<T : InvariantForLt>() {}
+///
+/// branded_api::<Brand>();
+/// ```
+///
+/// ```rust ,compile_fail
+/// use ::higher_kinded_types::extra_arities::*;
+///
+/// fn branded_api<T : InvariantForLt>() {}
+///
+/// branded_api::<::higher_kinded_types::ForRef<()>>(); // Error, not invariant.
+/// ```
+pub
+trait InvariantForLt : ForLt {}
+
+/// A [`ForLtAndLt`] which is, furthermore, covariant in its first lifetime
+/// parameter: `Self::Of<'_, 'b>` can be shrunk (over `'_`).
+///
+/// See [`CovariantForLtAndLt`] for more info.
+pub
+trait CovariantForLtAndLtInA : ForLtAndLt {
+    /// Shrinks the first lifetime parameter, leaving the second one untouched.
+    fn covariant_cast_a<'smol, 'humongous : 'smol, 'b>(
+        it: Self::Of<'humongous, 'b>,
+    ) -> Self::Of<'smol, 'b>
+    ;
+}
+
+/// A [`ForLtAndLt`] which is, furthermore, covariant in its second lifetime
+/// parameter: `Self::Of<'a, '_>` can be shrunk (over `'_`).
+///
+/// See [`CovariantForLtAndLt`] for more info.
+pub
+trait CovariantForLtAndLtInB : ForLtAndLt {
+    /// Shrinks the second lifetime parameter, leaving the first one untouched.
+    fn covariant_cast_b<'a, 'smol, 'humongous : 'smol>(
+        it: Self::Of<'a, 'humongous>,
+    ) -> Self::Of<'a, 'smol>
+    ;
+}
+
+/// A [`ForLtAndLt`] which is covariant in _both_ its lifetime parameters.
+///
+/// That is, a shorthand for <code>[CovariantForLtAndLtInA] +
+/// [CovariantForLtAndLtInB]</code>, which are to be used directly when only
+/// one of the parameters is covariant.
+///
+/// Such types are produced by the [`new_For_type!`] macro, by marking each
+/// covariant lifetime parameter with `#[covariant]`, or all of them at once
+/// with a leading `#![covariant]`.
+///
+/// ## Example
+///
+/// ```rust
+/// use {
+///     ::core::task::Context,
+///     ::higher_kinded_types::extra_arities::*,
+/// };
+///
+/// new_For_type! {
+///     /// `Context<'cx>` is invariant, but `&'r mut` is covariant in `'r`.
+///     type RefMutContext = For!(<#[covariant] 'r, 'cx> = &'r mut Context<'cx>);
+///
+///     type StrPair = For!(#![covariant] <'a, 'b> = (&'a str, &'b str));
+/// }
+///
+/// fn reborrow<'r, 'short, 'cx, T : CovariantForLtAndLtInA>(
+///     it: T::Of<'r, 'cx>,
+/// ) -> T::Of<'short, 'cx>
+/// where
+///     'r : 'short,
+/// {
+///     T::covariant_cast_a(it)
+/// }
+///
+/// fn shortest<'short, T : CovariantForLtAndLt>(
+///     it: T::Of<'static, 'short>,
+/// ) -> T::Of<'short, 'short>
+/// {
+///     T::covariant_cast_a(it)
+/// }
+///
+/// # fn _with(cx: &mut Context<'_>) {
+/// let _: &mut Context<'_> = reborrow::<RefMutContext>(cx);
+/// # }
+/// let local = String::from("local");
+/// let (a, b): (&str, &str) = shortest::<StrPair>(("static", &local));
+/// assert_eq!([a, b], ["static", "local"]);
+/// ```
+///
+/// ### Counter-example
+///
+/// ```rust ,compile_fail
+/// use {
+///     ::core::task::Context,
+///     ::higher_kinded_types::extra_arities::*,
+/// };
+///
+/// new_For_type! {
+///     type NotCov = For!(<'r, #[covariant] 'cx> = &'r mut Context<'cx>);
+/// }
+/// ```
+///
+/// fails with `'if_you_are_getting_this_error` diagnostics similar to the
+/// ones of [`CovariantForLt`].
+pub
+trait CovariantForLtAndLt : CovariantForLtAndLtInA + CovariantForLtAndLtInB {}
+
+impl<T : ?Sized> CovariantForLtAndLt for T
+where
+    T : CovariantForLtAndLtInA + CovariantForLtAndLtInB,
+{}
+
+/// A [`ForLtAndTy`] which is, furthermore, covariant in its lifetime parameter:
+/// `Self::Of<'_, T>` can be shrunk (over `'_`).
+///
+/// Such types are produced by the [`new_For_type!`] macro, by marking the
+/// lifetime parameter with `#[covariant]`, or with a leading `#![covariant]`.
+///
+/// Covariance in the type parameter is opt-in on its own: see
+/// [`CovariantForLtAndTyInT`].
+///
+/// ## Example
+///
+/// ```rust
+/// use ::higher_kinded_types::extra_arities::*;
+///
+/// new_For_type! {
+///     type ExclusiveRef = For!(#![covariant] <'r, T> = &'r mut T);
+///     type Slice = For!(<#[covariant] 'r, #[covariant] T> = &'r [T]);
+/// }
+///
+/// fn reborrow<'r, 'short, T : 'r, Ref : CovariantForLtAndTy>(
+///     it: Ref::Of<'r, T>,
+/// ) -> Ref::Of<'short, T>
+/// where
+///     'r : 'short,
+/// {
+///     Ref::covariant_cast(it)
+/// }
+///
+/// let mut x = 42;
+/// *reborrow::<i32, ExclusiveRef>(&mut x) += 27;
+/// assert_eq!(x, 42 + 27);
+///
+/// let local = String::from("local");
+/// let strs: &[&str] = &["static"];
+/// let strs: &[&str] = Slice::covariant_cast_t(strs);
+/// assert_eq!([strs[0], &local], ["static", "local"]);
+/// ```
+pub
+trait CovariantForLtAndTy : ForLtAndTy {
+    /// Shrinks the lifetime parameter, leaving the type parameter untouched.
+    fn covariant_cast<'smol, 'humongous : 'smol, T : 'humongous>(
+        it: Self::Of<'humongous, T>,
+    ) -> Self::Of<'smol, T>
+    ;
+}
+
+/// A [`ForLtAndTy`] which is, furthermore, covariant in its type parameter.
+///
+/// Since Rust features no `Sub : Super` bounds, this is expressed (and
+/// checked) as the ability to shrink a reference type parameter: `Self::Of<'lt,
+/// &'_ U>` can be shrunk (over `'_`).
+///
+/// See [`CovariantForLtAndTy`] for more info.
+pub
+trait CovariantForLtAndTyInT : ForLtAndTy {
+    /// Shrinks the lifetime of the `&U` type parameter, leaving the lifetime
+    /// parameter untouched.
+    fn covariant_cast_t<'lt, 'smol, 'humongous : 'smol, U : ?Sized + 'lt>(
+        it: Self::Of<'lt, &'humongous U>,
+    ) -> Self::Of<'lt, &'smol U>
+    where
+        'smol : 'lt,
+    ;
+}
+
+/// Variadic version of [`crate::ForLt!`], suitable for the [`For…` traits of
+/// this module][self#traits].
+///
+/// ### Syntax
+///
+///   - #### `ForLt`
+///
+///     ```rust
+///     # use ::higher_kinded_types::extra_arities::For;
+///     # mod some { pub use ::std::borrow::Cow as Arbitrary; }
+///     # use str as Type; let _:
+///     For!(<'r> = some::Arbitrary<'r, Type>)
+///     # ;
+///     ```
+///
+///   - #### `ForLtAndLt`
+///
+///     ```rust
+///     # use ::higher_kinded_types::extra_arities::For;
+///     # mod some { pub type Arbitrary<'a, 'b, T> = &'a &'b T; }
+///     # use str as Type; let _:
+///     For!(<'a, 'b> = some::Arbitrary<'a, 'b, Type>)
+///     # ;
+///     ```
+///
+///   - #### Shorthand syntax
+///
+///     Unlike with [`crate::ForLt!`], each anonymous/elided `'_` lifetime
+///     (or implicitly elided one, behind `&`) is a lifetime parameter of its
+///     own, in order of appearance, so that the number thereof picks the
+///     arity (`ForLt`, `ForLtAndLt`, …):
+///
+///     ```rust
+///     # use ::higher_kinded_types::extra_arities::For;
+///     # use ::core::task::Context; let _:
+///     For!(&mut Context<'_>) // same as `For!(<'a, 'b> = &'a mut Context<'b>)`
+///     # ;
+///     ```
+///
+///     To have several occurrences share a lifetime parameter, name it in a
+///     leading `<…>` (without the `=`); such named parameters come first:
+///
+///     ```rust
+///     # use ::higher_kinded_types::extra_arities::For;
+///     # let _:
+///     For!(<'s> (&'s str, &'s str, &[u8])) // `<'s, 'b> = (…, &'b [u8])`
+///     # ;
+///     ```
+///
+///   - #### `ForTy`
 ///
 ///       - ⚠️ to be used inside a [`new_For_type!`] invocation!
 ///
 ///     ```rust
 ///     # #[cfg(any())] macro_rules! ignore {
 ///     For!(<T> = Vec<T>)
+///     For!(<#[covariant] T> = Vec<T>) // `CovariantForTy`
+///     For!(<T : ?Sized> = Box<T>) // `ForUnsizedTy` too
+///     For!(<T : Ord> = BTreeSet<T>) // `ForTyOrd` instead
 ///     # }
 ///     ```
 ///
@@ -212,6 +1246,18 @@ trait CovariantForLt {
 ///     ```rust
 ///     # #[cfg(any())] macro_rules! ignore {
 ///     For!(<'r, T> = &'r mut T)
+///     For!(<'r, T : ?Sized> = &'r mut T) // `ForLtAndUnsizedTy` too
+///     # }
+///     ```
+///
+///   - #### `ForConst` and `ForLtAndConst`
+///
+///       - ⚠️ to be used inside a [`new_For_type!`] invocation!
+///
+///     ```rust
+///     # #[cfg(any())] macro_rules! ignore {
+///     For!(<const N: usize> = [u8; N])
+///     For!(<'r, const N: usize> = &'r mut [u8; N])
 ///     # }
 ///     ```
 ///
@@ -224,114 +1270,884 @@ trait CovariantForLt {
 ///     For!(#![covariant]<'r> = &'r mut String)
 ///     # }
 ///     ```
+///
+///   - #### Nominal `ForLt` and `ForLtAndLt`
+///
+///       - ⚠️ to be used inside a [`new_For_type!`] invocation!
+///
+///     ```rust
+///     # #[cfg(any())] macro_rules! ignore {
+///     For!(#![nominal]<'r> = &'r str)
+///     For!(#![nominal]<'a, 'b> = (&'a str, &'b str))
+///     # }
+///     ```
+///
+///       - (without it, a lifetime-only `For!` inside a [`new_For_type!`] is
+///         a mere `type` alias, for which two such declarations of the same
+///         type are then the same type).
+///
+///   - #### Higher arities
+///
+///     Up to four parameters, lifetimes first (_e.g._,
+///     [`ForLtAndLtAndLt`], [`ForTyAndTy`], [`ForLtAndLtAndTy`]…):
+///
+///     ```rust
+///     # use ::higher_kinded_types::extra_arities::For;
+///     # mod some { pub type Arbitrary<'a, 'b, 'c> = &'a &'b &'c (); }
+///     # let _:
+///     For!(<'a, 'b, 'c> = some::Arbitrary<'a, 'b, 'c>)
+///     # ;
+///     ```
+///
+///       - ⚠️ the ones involving types are to be used inside a
+///         [`new_For_type!`] invocation!
+///
+///     ```rust
+///     # #[cfg(any())] macro_rules! ignore {
+///     For!(<K, V> = HashMap<K, V>)
+///     For!(<'r, K, V> = &'r HashMap<K, V>)
+///     # }
+///     ```
+///
+///   - #### `ContravariantForLt` and `InvariantForLt`
+///
+///       - ⚠️ to be used inside a [`new_For_type!`] invocation!
+///
+///     ```rust
+///     # #[cfg(any())] macro_rules! ignore {
+///     For!(#![contravariant]<'r> = fn(&'r str))
+///     For!(#![invariant]<'r> = Cell<&'r str>)
+///     # }
+///     ```
+///
+///   - #### `CovariantForLtAndLt{,InA,InB}`
+///
+///       - ⚠️ to be used inside a [`new_For_type!`] invocation!
+///
+///     ```rust
+///     # #[cfg(any())] macro_rules! ignore {
+///     For!(#![covariant] <'a, 'b> = (&'a str, &'b str))
+///     For!(<#[covariant] 'r, 'cx> = &'r mut Context<'cx>)
+///     For!(<'cx, #[covariant] 'r> = &'r mut Context<'cx>)
+///     # }
+///     ```
+///
+///   - #### `CovariantForLtAndTy{,InT}`
+///
+///       - ⚠️ to be used inside a [`new_For_type!`] invocation!
+///
+///     ```rust
+///     # #[cfg(any())] macro_rules! ignore {
+///     For!(#![covariant] <'r, T> = &'r mut T)
+///     For!(<#[covariant] 'r, #[covariant] T> = &'r [T])
+///     For!(<'r, #[covariant] T> = Cell<&'r T>)
+///     # }
+///     ```
+///
+///       - the leading `#![covariant]` is a shorthand for `#[covariant]`
+///         on every _lifetime_ parameter.
 #[macro_export] #[doc(hidden)]
 macro_rules! ඞFor_ {
     (
-        $($implementation_details_hidden:tt)*
+        $($implementation_details_hidden:tt)*
+    ) => (
+        $crate::ඞFor! { $($implementation_details_hidden)* }
+    );
+} #[doc(inline)] pub use ඞFor_ as For;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ඞFor {
+    (
+        <$lt:lifetime> = $Type:ty $(,)?
+    ) => (
+        $crate::ForLt! { <$lt> = $Type }
+    );
+
+    (
+        <$a:lifetime, $b:lifetime> = $Type:ty $(,)?
+    ) => (
+        $crate::ඞ::ForLtAndLt<
+            dyn for<$a, $b> $crate::ඞ::WithLifetimes<$a, $b, T = $Type>,
+        >
+    );
+
+    (
+        <$a:lifetime, $b:lifetime, $c:lifetime> = $Type:ty $(,)?
+    ) => (
+        $crate::ඞ::for_lt_and_lt_and_lt::ForLtAndLtAndLt<
+            dyn for<$a, $b, $c>
+                $crate::ඞ::for_lt_and_lt_and_lt::WithLifetimes<$a, $b, $c, T = $Type>
+            ,
+        >
+    );
+
+    (
+        <$a:lifetime, $b:lifetime, $c:lifetime, $d:lifetime> = $Type:ty $(,)?
+    ) => (
+        $crate::ඞ::for_lt_and_lt_and_lt_and_lt::ForLtAndLtAndLtAndLt<
+            dyn for<$a, $b, $c, $d>
+                $crate::ඞ::for_lt_and_lt_and_lt_and_lt::WithLifetimes<
+                    $a, $b, $c, $d, T = $Type,
+                >
+            ,
+        >
+    );
+
+    (
+        <
+            $($lt:lifetime ,)*
+            $($T:ident $(: $(?$Sized:ident)? $($Bound:ident)? $(+ $Bounds:ident)*)?),+
+            $(,)?
+        > = $Type:ty $(,)?
+    ) => (
+        ::core::compile_error! { concat!("Usage:
+new_For_type! {
+    /// Attrs…
+    pub type TypeName = For!(
+        ", stringify!(
+            <$($lt ,)* $($T $(: $(?$Sized)? $($Bound)? $(+ $Bounds)*)?),+> = $Type
+        ), "
+    );
+}\
+        ")}
+    );
+
+    (
+        <$($lt:lifetime ,)? const $N:ident : usize $(,)?> = $Type:ty $(,)?
+    ) => (
+        ::core::compile_error! { concat!("Usage:
+new_For_type! {
+    /// Attrs…
+    pub type TypeName = For!(
+        ", stringify!(<$($lt ,)? const $N: usize> = $Type), "
+    );
+}\
+        ")}
+    );
+
+    (
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        <const $N:ident : usize $(,)?> = $Type:ty $(,)?
+    ) => (
+        $crate::ඞFor! { @struct[$(#[$attr])*] $pub $Name $generics }
+
+        $crate::ඞFor! {
+            @impl[] $generics $crate::extra_arities::ForConst, $Name [] {
+                type Of<const $N : usize> = $Type;
+            }
+        }
+    );
+
+    (
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        <$lt:lifetime, const $N:ident : usize $(,)?> = $Type:ty $(,)?
+    ) => (
+        $crate::ඞFor! { @struct[$(#[$attr])*] $pub $Name $generics }
+
+        $crate::ඞFor! {
+            @impl[] $generics $crate::extra_arities::ForLtAndConst, $Name [] {
+                type Of<$lt, const $N : usize> = $Type;
+            }
+        }
+    );
+
+    (
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        #![covariant] <$T:ident $(: ?$Sized:ident)?> = $Type:ty $(,)?
+    ) => (
+        $crate::ඞFor! {
+            #[name($pub $Name $generics)]
+            $(#[$attr])*
+            <#[covariant] $T $(: ?$Sized)?> = $Type
+        }
+    );
+
+    (
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        <$T:ident : $Bound:ident $(+ $Bounds:ident)* $(,)?> = $Type:ty $(,)?
+    ) => (
+        $crate::ඞFor! { @struct[$(#[$attr])*] $pub $Name $generics }
+
+        $crate::ඞ::paste! {
+            $crate::ඞFor! {
+                @impl[] $generics [< ForTy $Bound $($Bounds)* >], $Name [] {
+                    type Of<$T : $Bound $(+ $Bounds)*> = $Type;
+                }
+            }
+        }
+    );
+
+    (
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        <
+            $(#[$T_variance:ident])? $T:ident
+            $(: ?Sized $(@$if_unsized:tt)?)? $(,)?
+        > = $Type:ty $(,)?
+    ) => (
+        $crate::ඞFor! { @struct[$(#[$attr])*] $pub $Name $generics }
+
+        $crate::ඞFor! {
+            @impl[] $generics $crate::extra_arities::ForTy, $Name [] {
+                type Of<$T> = $Type;
+
+                $crate::ඞFor! { @type_name[$T] $Type }
+            }
+        }
+
+        $($($if_unsized)?
+            $crate::ඞFor! {
+                @impl[] $generics $crate::extra_arities::ForUnsizedTy, $Name [] {
+                    type Of<$T : ?Sized> = $Type;
+
+                    $crate::ඞFor! { @type_name[$T] $Type }
+                }
+            }
+        )?
+
+        $(
+            $crate::ඞFor! {
+                @variance[$T_variance] CovariantInTy for $Name $generics
+                <$T> = $Type
+            }
+        )?
+    );
+
+    (
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        #![covariant] <$lt:lifetime, $T:ident $(: ?$Sized:ident)?> = $Type:ty $(,)?
+    ) => (
+        $crate::ඞFor! {
+            #[name($pub $Name $generics)]
+            $(#[$attr])*
+            <#[covariant] $lt, $T $(: ?$Sized)?> = $Type
+        }
+    );
+
+    (
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        <
+            $(#[$lt_variance:ident])? $lt:lifetime,
+            $(#[$T_variance:ident])? $T:ident
+            $(: ?Sized $(@$if_unsized:tt)?)? $(,)?
+        > = $Type:ty $(,)?
+    ) => (
+        $crate::ඞFor! { @struct[$(#[$attr])*] $pub $Name $generics }
+
+        $crate::ඞFor! {
+            @impl[] $generics $crate::extra_arities::ForLtAndTy, $Name [] {
+                type Of<$lt, $T : $lt> = $Type;
+
+                $crate::ඞFor! { @type_name[$lt, $T] $Type }
+            }
+        }
+
+        $($($if_unsized)?
+            $crate::ඞFor! {
+                @impl[] $generics $crate::extra_arities::ForLtAndUnsizedTy, $Name [] {
+                    type Of<$lt, $T : ?Sized + $lt> = $Type;
+
+                    $crate::ඞFor! { @type_name[$lt, $T] $Type }
+                }
+            }
+        )?
+
+        $(
+            $crate::ඞFor! {
+                @variance[$lt_variance] CovariantInLt for $Name $generics
+                <$lt, $T> = $Type
+            }
+        )?
+        $(
+            $crate::ඞFor! {
+                @variance[$T_variance] CovariantInT for $Name $generics
+                <$lt, $T> = $Type
+            }
+        )?
+    );
+
+    (
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        <$($lt:lifetime,)* $($T:ident),+ $(,)?> = $Type:ty $(,)?
+    ) => (
+        $crate::ඞFor! { @struct[$(#[$attr])*] $pub $Name $generics }
+
+        $crate::ඞFor! {
+            @arity[$($lt)*][$($T)*] for $Name $generics [$($lt,)* $($T),+] = $Type
+        }
+    );
+
+    // `For…` trait lookup, by number of lifetime and type parameters.
+    (
+        @arity[$a:lifetime][$T:ident $U:ident] $($rest:tt)*
+    ) => (
+        $crate::ඞFor! {
+            @generics[$a][][$T $U] ForLtAndTyAndTy $($rest)*
+        }
+    );
+    (
+        @arity[$a:lifetime][$T:ident $U:ident $V:ident] $($rest:tt)*
+    ) => (
+        $crate::ඞFor! {
+            @generics[$a][][$T $U $V] ForLtAndTyAndTyAndTy $($rest)*
+        }
+    );
+    (
+        @arity[$a:lifetime $b:lifetime][$T:ident] $($rest:tt)*
+    ) => (
+        $crate::ඞFor! {
+            @generics[$a $b][][$T] ForLtAndLtAndTy $($rest)*
+        }
+    );
+    (
+        @arity[$a:lifetime $b:lifetime][$T:ident $U:ident] $($rest:tt)*
+    ) => (
+        $crate::ඞFor! {
+            @generics[$a $b][][$T $U] ForLtAndLtAndTyAndTy $($rest)*
+        }
+    );
+    (
+        @arity[$a:lifetime $b:lifetime $c:lifetime][$T:ident] $($rest:tt)*
+    ) => (
+        $crate::ඞFor! {
+            @generics[$a $b $c][][$T] ForLtAndLtAndLtAndTy $($rest)*
+        }
+    );
+    (
+        @arity[][$T:ident $U:ident] $($rest:tt)*
+    ) => (
+        $crate::ඞFor! {
+            @generics[][][$T $U] ForTyAndTy $($rest)*
+        }
+    );
+    (
+        @arity[][$T:ident $U:ident $V:ident] $($rest:tt)*
+    ) => (
+        $crate::ඞFor! {
+            @generics[][][$T $U $V] ForTyAndTyAndTy $($rest)*
+        }
+    );
+    (
+        @arity[][$T:ident $U:ident $V:ident $W:ident] $($rest:tt)*
+    ) => (
+        $crate::ඞFor! {
+            @generics[][][$T $U $V $W] ForTyAndTyAndTyAndTy $($rest)*
+        }
+    );
+    (
+        @arity[$($lt:lifetime)*][$($T:ident)*] $($rest:tt)*
+    ) => (
+        ::core::compile_error! { ::core::concat!(
+            "unsupported arity: `<", $(::core::stringify!($lt), ", ",)*
+            $(::core::stringify!($T), ", ",)* ">` (up to 4 parameters are \
+            supported, as well as `#[covariant]` annotations for the ones of \
+            the `ForLt`, `ForLtAndLt`, `ForTy` and `ForLtAndTy` arities)",
+        )}
+    );
+
+    // every type parameter is to outlive every lifetime one.
+    (
+        @generics[$($lt:lifetime)*][$($acc:tt)*][$T:ident $($rest:ident)*]
+        $($cont:tt)*
+    ) => (
+        $crate::ඞFor! {
+            @generics[$($lt)*][$($acc)* $T : $($lt +)* ,][$($rest)*]
+            $($cont)*
+        }
+    );
+    (
+        @generics[$($lt:lifetime)*][$($acc:tt)*][]
+        $Trait:ident for $Name:ident $generics:tt $params:tt = $Type:ty
+    ) => (
+        $crate::ඞFor! {
+            @impl[] $generics $crate::extra_arities::$Trait, $Name [] {
+                type Of<$($lt ,)* $($acc)*> = $Type;
+
+                $crate::ඞFor! { @type_name $params $Type }
+            }
+        }
+    );
+
+    (
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        #![nominal] <$a:lifetime, $b:lifetime> = $Type:ty $(,)?
+    ) => (
+        $crate::ඞFor! {
+            @lt_and_lt
+            #[name($pub $Name $generics)]
+            $(#[$attr])*
+            <$a, $b> = $Type
+        }
+    );
+
+    (
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        #![covariant] <$a:lifetime, $b:lifetime> = $Type:ty $(,)?
     ) => (
-        $crate::ඞFor! { $($implementation_details_hidden)* }
+        $crate::ඞFor! {
+            #[name($pub $Name $generics)]
+            $(#[$attr])*
+            <#[covariant] $a, #[covariant] $b> = $Type
+        }
     );
-} #[doc(inline)] pub use ඞFor_ as For;
 
-#[doc(hidden)]
-#[macro_export]
-macro_rules! ඞFor {
     (
-        <$lt:lifetime> = $Type:ty $(,)?
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        <#[$a_variance:ident] $a:lifetime, $($rest:tt)*
     ) => (
-        $crate::ForLt! { <$lt> = $Type }
+        $crate::ඞFor! {
+            @lt_and_lt
+            #[name($pub $Name $generics)]
+            $(#[$attr])*
+            <#[$a_variance] $a, $($rest)*
+        }
     );
 
     (
-        <$a:lifetime, $b:lifetime> = $Type:ty $(,)?
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        <$a:lifetime, #[$b_variance:ident] $($rest:tt)*
     ) => (
-        $crate::ඞ::ForLtAndLt<
-            dyn for<$a, $b> $crate::ඞ::WithLifetimes<$a, $b, T = $Type>,
-        >
+        $crate::ඞFor! {
+            @lt_and_lt
+            #[name($pub $Name $generics)]
+            $(#[$attr])*
+            <$a, #[$b_variance] $($rest)*
+        }
     );
 
     (
-        <$($lt:lifetime ,)? $T:ident> = $Type:ty $(,)?
+        @lt_and_lt
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        <
+            $(#[$a_variance:ident])? $a:lifetime,
+            $(#[$b_variance:ident])? $b:lifetime $(,)?
+        > = $Type:ty $(,)?
     ) => (
-        ::core::compile_error! { concat!("Usage:
-new_For_type! {
-    /// Attrs…
-    pub type TypeName = For!(
-        ", stringify!(<$($lt ,)? $T> = $Type), "
+        $crate::ඞFor! { @struct[$(#[$attr])*] $pub $Name $generics }
+
+        $crate::ඞFor! { @impl[] $generics $crate::ඞ::Nominal, $Name [] {} }
+
+        // The (trivially true) `where` clause keeps `Of` from being normalized
+        // when checking its well-formedness, which would otherwise require,
+        // say, `'cx : 'r` for `&'r mut Context<'cx>`.
+        $crate::ඞFor! {
+            @impl[] $generics $crate::extra_arities::ForLtAndLt, $Name [
+                $crate::ඞ::ForLtAndLt<
+                    dyn for<$a, $b> $crate::ඞ::WithLifetimes<$a, $b, T = $Type>,
+                > : $crate::extra_arities::ForLtAndLt,
+            ]
+            {
+                type Of<'ඞa, 'ඞb> = <
+                    $crate::ඞ::ForLtAndLt<
+                        dyn for<$a, $b> $crate::ඞ::WithLifetimes<$a, $b, T = $Type>,
+                    >
+                    as
+                    $crate::extra_arities::ForLtAndLt
+                >::Of<'ඞa, 'ඞb>;
+
+                $crate::ඞFor! { @type_name[$a, $b] $Type }
+            }
+        }
+
+        $(
+            $crate::ඞFor! {
+                @variance[$a_variance] CovariantInA for $Name $generics
+                <$a, $b> = $Type
+            }
+        )?
+        $(
+            $crate::ඞFor! {
+                @variance[$b_variance] CovariantInB for $Name $generics
+                <$a, $b> = $Type
+            }
+        )?
     );
-}\
-        ")}
+
+    (
+        @variance[contravariant] ForLt for $Name:ident $generics:tt
+    ) => (
+        $crate::ඞFor! {
+            @impl[] $generics $crate::extra_arities::ContravariantForLt, $Name [] {
+                #[inline]
+                fn contravariant_cast<
+                    'if_you_are_getting_this_error,
+                    'it_means_your_type_is_not_contravariant
+                        : 'if_you_are_getting_this_error,
+                >(
+                    it: <Self as $crate::ForLt>::Of<'if_you_are_getting_this_error>,
+                ) -> <Self as $crate::ForLt>::Of<'it_means_your_type_is_not_contravariant>
+                {
+                    it
+                }
+            }
+        }
     );
 
     (
-        #[name($pub:tt $Name:ident)]
-        $(#$attr:tt)*
-        <$T:ident> = $Type:ty $(,)?
+        @variance[invariant] ForLt for $Name:ident $generics:tt
     ) => (
-        $(#$attr)*
-        $pub
-        struct $Name(fn(&()) -> &mut Self);
+        $crate::ඞFor! {
+            @impl[] $generics $crate::extra_arities::InvariantForLt, $Name [] {}
+        }
+    );
 
-        impl $crate::extra_arities::ForTy for $Name {
-            type Of<$T> = $Type;
+    // The per-parameter casts, whose `it` bodies are the very covariance
+    // checks (lifetimes renamed for hopefully nicer diagnostics).
+    (
+        @variance[covariant] CovariantInTy for $Name:ident $generics:tt
+        <$T:ident> = $Type:ty
+    ) => (
+        $crate::ඞFor! {
+            @impl[] $generics $crate::extra_arities::CovariantForTy, $Name [] {
+                #[inline]
+                fn covariant_cast<
+                    'if_you_are_getting_this_error,
+                    'it_means_your_type_is_not_covariant
+                        : 'if_you_are_getting_this_error,
+                    ඞU : ?Sized,
+                >(
+                    it: <Self as $crate::extra_arities::ForTy>::Of<
+                        &'it_means_your_type_is_not_covariant ඞU,
+                    >,
+                ) -> <Self as $crate::extra_arities::ForTy>::Of<
+                    &'if_you_are_getting_this_error ඞU,
+                >
+                {
+                    it
+                }
+            }
         }
     );
 
+    // (the lifetimes are named as in `$Type`, so that the implied bounds of,
+    // say, `&'r mut Context<'cx>`, are visible to Rust 1.65; the untouched one
+    // is made early-bound, as in the trait, by the empty `:` bound).
     (
-        #[name($pub:tt $Name:ident)]
-        $(#$attr:tt)*
-        <$lt:lifetime, $T:ident> = $Type:ty $(,)?
+        @variance[covariant] CovariantInA for $Name:ident $generics:tt
+        <$a:lifetime, $b:lifetime> = $Type:ty
     ) => (
-        $(#$attr)*
-        $pub
-        struct $Name(fn(&()) -> &mut Self);
+        $crate::ඞFor! {
+            @impl[] $generics $crate::extra_arities::CovariantForLtAndLtInA, $Name [] {
+                #[inline]
+                fn covariant_cast_a<
+                    'if_you_are_getting_this_error,
+                    $a : 'if_you_are_getting_this_error,
+                    $b,
+                >(
+                    it: $Type,
+                ) -> <Self as $crate::extra_arities::ForLtAndLt>::Of<
+                    'if_you_are_getting_this_error, $b,
+                >
+                where
+                    $b :,
+                {
+                    it
+                }
+            }
+        }
+    );
+
+    (
+        @variance[covariant] CovariantInB for $Name:ident $generics:tt
+        <$a:lifetime, $b:lifetime> = $Type:ty
+    ) => (
+        $crate::ඞFor! {
+            @impl[] $generics $crate::extra_arities::CovariantForLtAndLtInB, $Name [] {
+                #[inline]
+                fn covariant_cast_b<
+                    $a,
+                    'if_you_are_getting_this_error,
+                    $b : 'if_you_are_getting_this_error,
+                >(
+                    it: $Type,
+                ) -> <Self as $crate::extra_arities::ForLtAndLt>::Of<
+                    $a, 'if_you_are_getting_this_error,
+                >
+                where
+                    $a :,
+                {
+                    it
+                }
+            }
+        }
+    );
+
+    (
+        @variance[covariant] CovariantInLt for $Name:ident $generics:tt
+        <$lt:lifetime, $T:ident> = $Type:ty
+    ) => (
+        $crate::ඞFor! {
+            @impl[] $generics $crate::extra_arities::CovariantForLtAndTy, $Name [] {
+                #[inline]
+                fn covariant_cast<
+                    'if_you_are_getting_this_error,
+                    'it_means_your_type_is_not_covariant
+                        : 'if_you_are_getting_this_error,
+                    ඞT : 'it_means_your_type_is_not_covariant,
+                >(
+                    it: <Self as $crate::extra_arities::ForLtAndTy>::Of<
+                        'it_means_your_type_is_not_covariant, ඞT,
+                    >,
+                ) -> <Self as $crate::extra_arities::ForLtAndTy>::Of<
+                    'if_you_are_getting_this_error, ඞT,
+                >
+                {
+                    it
+                }
+            }
+        }
+    );
 
-        impl $crate::extra_arities::ForLtAndTy for $Name {
-            type Of<$lt, $T : $lt> = $Type;
+    (
+        @variance[covariant] CovariantInT for $Name:ident $generics:tt
+        <$lt:lifetime, $T:ident> = $Type:ty
+    ) => (
+        $crate::ඞFor! {
+            @impl[] $generics $crate::extra_arities::CovariantForLtAndTyInT, $Name [] {
+                #[inline]
+                fn covariant_cast_t<
+                    'ඞlt,
+                    'if_you_are_getting_this_error,
+                    'it_means_your_type_is_not_covariant
+                        : 'if_you_are_getting_this_error,
+                    ඞU : ?Sized + 'ඞlt,
+                >(
+                    it: <Self as $crate::extra_arities::ForLtAndTy>::Of<
+                        'ඞlt, &'it_means_your_type_is_not_covariant ඞU,
+                    >,
+                ) -> <Self as $crate::extra_arities::ForLtAndTy>::Of<
+                    'ඞlt, &'if_you_are_getting_this_error ඞU,
+                >
+                where
+                    'if_you_are_getting_this_error : 'ඞlt,
+                {
+                    it
+                }
+            }
         }
     );
 
+    // `#![nominal]`: the nominal type alone, with no variance claim.
+    (
+        @variance[nominal] ForLt for $($_:tt)*
+    ) => ();
+
+    (
+        @variance[$unknown:ident] $($_:tt)*
+    ) => (
+        ::core::compile_error! { ::core::concat!(
+            "unsupported variance annotation `", ::core::stringify!($unknown),
+            "` (for this arity)",
+        )}
+    );
+
     (
-        #[name($pub:tt $Name:ident)]
+        #[name($pub:tt $Name:ident $generics:tt)]
         $(#[$attr:meta])*
         #![covariant] <$lt:lifetime> = $Type:ty $(,)?
     ) => (
-        $(#[$attr])*
-        $pub
-        struct $Name(fn(&()) -> &mut Self);
+        $crate::ඞFor! { @struct[$(#[$attr])*] $pub $Name $generics }
 
-        impl $crate::extra_arities::CovariantForLt for $Name {
-            type Of<$lt> = $Type
-            where
-                Self : $lt,
-            ;
+        $crate::ඞFor! {
+            @impl[] $generics $crate::extra_arities::CovariantForLt, $Name [] {
+                type Of<$lt> = $Type
+                where
+                    Self : $lt,
+                ;
 
-            // lifetimes renamed for hopefully nicer diagnostics
-            #[inline]
-            fn covariant_cast<
-                'if_you_are_getting_this_error,
-                'it_means_your_type_is_not_covariant,
-            >(
-                it: Self::Of<'it_means_your_type_is_not_covariant>,
-            ) -> Self::Of<'if_you_are_getting_this_error>
-            where
-                Self : 'if_you_are_getting_this_error
-                     + 'it_means_your_type_is_not_covariant,
-                'it_means_your_type_is_not_covariant
-                    : 'if_you_are_getting_this_error,
-            {
-                it
+                // lifetimes renamed for hopefully nicer diagnostics
+                #[inline]
+                fn covariant_cast<
+                    'if_you_are_getting_this_error,
+                    'it_means_your_type_is_not_covariant,
+                >(
+                    it: Self::Of<'it_means_your_type_is_not_covariant>,
+                ) -> Self::Of<'if_you_are_getting_this_error>
+                where
+                    Self : 'if_you_are_getting_this_error
+                         + 'it_means_your_type_is_not_covariant,
+                    'it_means_your_type_is_not_covariant
+                        : 'if_you_are_getting_this_error,
+                {
+                    it
+                }
+            }
+        }
+    );
+
+    (
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        #![$variance:ident] <$lt:lifetime> = $Type:ty $(,)?
+    ) => (
+        $crate::ඞFor! { @struct[$(#[$attr])*] $pub $Name $generics }
+
+        $crate::ඞFor! {
+            @impl[$lt,] $generics $crate::ඞ::WithLifetime<$lt>, $Name [] {
+                type T = $Type;
+
+                $crate::ඞFor! { @type_name[$lt] $Type }
             }
         }
+
+        $crate::ඞFor! { @impl[] $generics $crate::ඞ::Nominal, $Name [] {} }
+
+        $crate::ඞFor! { @variance[$variance] ForLt for $Name $generics }
     );
 
     (
-        #[name($pub:tt $Name:ident)]
+        #[name($pub:tt $Name:ident $generics:tt)]
         $(#$attr:tt)*
         < $($rest:tt)*
     ) => (
-        $(#$attr)*
-        $pub type $Name = $crate::extra_arities::For!(< $($rest)* );
+        $crate::ඞFor! {
+            @alias[$(#$attr)*] $pub $Name $generics
+            = $crate::extra_arities::For!(< $($rest)* )
+        }
+    );
+
+    // The `hkt_type_name()` of a nominal type: its source, as written.
+    (
+        @type_name[$first:tt $(, $param:tt)*] $Type:ty
+    ) => (
+        #[inline]
+        fn ඞtype_name() -> ::core::option::Option<&'static str> {
+            ::core::option::Option::Some(::core::concat!(
+                "for<", ::core::stringify!($first), $(", ", ::core::stringify!($param),)*
+                "> ", ::core::stringify!($Type),
+            ))
+        }
+    );
+
+    // Emission of the items of a nominal type, given the
+    // `[[lifetime params…] [other params…] [lifetime args…] [other args…]]`
+    // generics of its name (as parsed by `new_For_type!`).
+    (
+        @struct[$($attr:tt)*] $pub:tt $Name:ident
+        [[$($lt:tt)*] [$($ty:tt)*] $($_:tt)*]
+    ) => (
+        $($attr)*
+        $pub
+        struct $Name<$($lt)* $($ty)*>(fn(&()) -> &mut Self);
+    );
+
+    (
+        @impl[$($extra:tt)*]
+        [[$($lt:tt)*] [$($ty:tt)*] [$($lt_arg:tt)*] [$($ty_arg:tt)*]]
+        $Trait:path, $Name:ident [$($where:tt)*]
+        { $($body:tt)* }
+    ) => (
+        impl<$($extra)* $($lt)* $($ty)*>
+            $Trait
+        for
+            $Name<$($lt_arg)* $($ty_arg)*>
+        where
+            $($where)*
+        {
+            $($body)*
+        }
+    );
+
+    (
+        @alias[$($attr:tt)*] $pub:tt $Name:ident
+        [[$($lt:tt)*] [$($ty:tt)*] $($_:tt)*]
+        = $T:ty
+    ) => (
+        $($attr)*
+        #[allow(type_alias_bounds)]
+        $pub
+        type $Name<$($lt)* $($ty)*> = $T;
+    );
+
+    // Shorthand syntax: each `'_` (or elided `&`) is a lifetime parameter of
+    // its own, after the (shared) named ones, if any.
+    (
+        #[name($pub:tt $Name:ident $generics:tt)]
+        $(#[$attr:meta])*
+        $($shorthand:tt)+
+    ) => (
+        $crate::ඞFor! {
+            @alias[$(#[$attr])*] $pub $Name $generics
+            = $crate::extra_arities::For!($($shorthand)+)
+        }
+    );
+
+    (
+        <$($named:lifetime),+ $(,)?> $($shorthand:tt)+
+    ) => (
+        $crate::ඞFor_munch! {
+            [fresh: 'ඞ0 'ඞ1 'ඞ2 'ඞ3 /* ' */]
+            [used: $($named)+]
+            [stack: ]
+            [mode: default]
+            [output: ]
+            [input: $($shorthand)+]
+        }
+    );
+
+    (
+        @shorthand[
+            $a:lifetime $($b:lifetime $($c:lifetime $($d:lifetime)?)?)?
+        ]
+        $Type:ty $(,)?
+    ) => (
+        $crate::ඞFor! { <$a $(, $b $(, $c $(, $d)?)?)?> = $Type }
+    );
+
+    (
+        @shorthand[] $($Type:tt)*
+    ) => (
+        ::core::compile_error! { ::core::concat!(
+            "no `'_` (nor elided `&`) lifetime in `For!(",
+            ::core::stringify!($($Type)*), ")`",
+        )}
+    );
+
+    (
+        @shorthand[
+            $a:lifetime $b:lifetime $c:lifetime $d:lifetime $e:lifetime
+            $($more:lifetime)*
+        ]
+        $($Type:tt)*
+    ) => (
+        ::core::compile_error! {
+            "too many lifetimes in `For!` (up to 4 are supported)"
+        }
+    );
+
+    (
+        @shorthand[$($lt:lifetime)*] $($Type:tt)*
+    ) => (
+        ::core::compile_error! { ::core::concat!(
+            "expected a type in `For!`, got `", ::core::stringify!($($Type)*),
+            "`",
+        )}
+    );
+
+    (
+        $($shorthand:tt)+
+    ) => (
+        $crate::ඞFor_munch! {
+            [fresh: 'ඞ0 'ඞ1 'ඞ2 'ඞ3 /* ' */]
+            [used: ]
+            [stack: ]
+            [mode: default]
+            [output: ]
+            [input: $($shorthand)+]
+        }
     );
 }
 
@@ -469,28 +2285,272 @@ new_For_type! {
 /// //                                          vvv
 /// vec_for_each::<i32, Owned>(vec, |_, _owned: i32| {});
 /// ```
+///
+/// ### Nominal lifetime-only families
+///
+/// A lifetime-only `For!(<'r> = …)` is otherwise a mere `type` alias of some
+/// [`ForLt!`][crate::ForLt!] type, so that two such declarations for the same
+/// type are the same type. `#![nominal]` makes it a fresh type instead, which
+/// can thus have its own impls:
+///
+/// ```rust
+/// use ::higher_kinded_types::{extra_arities::*, ForLt};
+///
+/// new_For_type! {
+///     /// Docs of its own.
+///     pub type Name = For!(#![nominal] <'r> = &'r str);
+///     pub type Path = For!(#![nominal] <'r> = &'r str);
+/// }
+///
+/// impl ::core::fmt::Debug for Name {
+///     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+///         f.write_str("Name")
+///     }
+/// }
+///
+/// trait Describe { const KIND: &'static str; }
+/// impl Describe for Name { const KIND: &'static str = "name"; }
+/// impl Describe for Path { const KIND: &'static str = "path"; }
+///
+/// fn describe<'r, T : ForLt + Describe>(it: T::Of<'r>) -> (&'static str, T::Of<'r>) {
+///     (T::KIND, it)
+/// }
+///
+/// assert_eq!(describe::<Name>("ferris").0, "name");
+/// assert_eq!(describe::<Path>("/tmp").0, "path");
+/// ```
+///
+/// ### Generic parameters
+///
+/// The declared type may itself be generic, over lifetime, type, and `const`
+/// parameters (with inline bounds), which are then forwarded to the `For…`
+/// impls (and variance checks) of the so-defined type:
+///
+/// ```rust
+/// use {
+///     ::std::{collections::HashMap, hash::Hash},
+///     ::higher_kinded_types::extra_arities::*,
+/// };
+///
+/// new_For_type! {
+///     pub type MapWithKey<K : Hash + Eq> = For!(<V> = HashMap<K, V>);
+///     pub type ResultWith<E> = For!(<T> = Result<T, E>);
+///     pub type Array<const N : usize> = For!(<T> = [T; N]);
+///     pub type StrRefWith<'s> = For!(#![covariant] <'r> = (&'r str, &'s str));
+/// }
+///
+/// fn demo<F : ForTy>(_: F::Of<bool>) {}
+///
+/// demo::<MapWithKey<String>>(HashMap::from([("true".into(), true)]));
+/// demo::<ResultWith<String>>(Ok(true));
+/// demo::<Array<2>>([true, false]);
+/// ```
 #[apply(macro_export)]
 macro_rules! new_For_type {
-    ($(
-        $( #$attr:tt )*
-        $pub:vis
-        type $Name:ident =
+    () => ();
+
+    (
+        @emit[$($attr:tt)*] $pub:tt $Name:ident $generics:tt =
             $($(@$if_leading:tt)?
                 ::
             )?
             $($macro:ident)::+ ! ( $($args:tt)* )
         ;
-    )*) => ($(
+        $($rest:tt)*
+    ) => (
         $($($if_leading)? :: )? $($macro)::+ ! {
-            #[name($pub $Name)]
-            $(#$attr)*
+            #[name($pub $Name $generics)]
+            $($attr)*
             $($args)*
         }
-    )*);
+
+        $crate::ඞnew_For_type! { $($rest)* }
+    );
+
+    // Generics munching, so as to split them into lifetime parameters, other
+    // parameters, and the corresponding arguments, given:
+    // `@generics[…] $pub $Name [lts…] [tys…] [lt args…] [ty args…] [current]`,
+    // where `current` is the parameter being munched (with its `<…>` depth),
+    // if any.
+    (
+        @generics $item:tt $pub:tt $Name:ident
+        [$($lt:tt)*] [$($ty:tt)*] [$($lt_arg:tt)*] [$($ty_arg:tt)*] []
+        > $($rest:tt)*
+    ) => (
+        $crate::ඞnew_For_type! {
+            @emit $item $pub $Name
+            [[$($lt)*] [$($ty)*] [$($lt_arg)*] [$($ty_arg)*]]
+            $($rest)*
+        }
+    );
+
+    (
+        @generics $item:tt $pub:tt $Name:ident
+        $lts:tt $tys:tt [$($lt_arg:tt)*] $ty_args:tt []
+        $param:lifetime $($rest:tt)*
+    ) => (
+        $crate::ඞnew_For_type! {
+            @generics $item $pub $Name
+            $lts $tys [$($lt_arg)* $param,] $ty_args [lt [] $param]
+            $($rest)*
+        }
+    );
+
+    (
+        @generics $item:tt $pub:tt $Name:ident
+        $lts:tt $tys:tt $lt_args:tt [$($ty_arg:tt)*] []
+        const $param:ident $($rest:tt)*
+    ) => (
+        $crate::ඞnew_For_type! {
+            @generics $item $pub $Name
+            $lts $tys $lt_args [$($ty_arg)* $param,] [ty [] const $param]
+            $($rest)*
+        }
+    );
+
+    (
+        @generics $item:tt $pub:tt $Name:ident
+        $lts:tt $tys:tt $lt_args:tt [$($ty_arg:tt)*] []
+        $param:ident $($rest:tt)*
+    ) => (
+        $crate::ඞnew_For_type! {
+            @generics $item $pub $Name
+            $lts $tys $lt_args [$($ty_arg)* $param,] [ty [] $param]
+            $($rest)*
+        }
+    );
+
+    // End of a (lifetime or not) parameter.
+    (
+        @generics $item:tt $pub:tt $Name:ident
+        [$($lt:tt)*] $tys:tt $lt_args:tt $ty_args:tt [lt [] $($param:tt)*]
+        , $($rest:tt)*
+    ) => (
+        $crate::ඞnew_For_type! {
+            @generics $item $pub $Name
+            [$($lt)* $($param)* ,] $tys $lt_args $ty_args []
+            $($rest)*
+        }
+    );
+
+    (
+        @generics $item:tt $pub:tt $Name:ident
+        [$($lt:tt)*] $tys:tt $lt_args:tt $ty_args:tt [lt [] $($param:tt)*]
+        > $($rest:tt)*
+    ) => (
+        $crate::ඞnew_For_type! {
+            @generics $item $pub $Name
+            [$($lt)* $($param)* ,] $tys $lt_args $ty_args []
+            > $($rest)*
+        }
+    );
+
+    (
+        @generics $item:tt $pub:tt $Name:ident
+        $lts:tt [$($ty:tt)*] $lt_args:tt $ty_args:tt [ty [] $($param:tt)*]
+        , $($rest:tt)*
+    ) => (
+        $crate::ඞnew_For_type! {
+            @generics $item $pub $Name
+            $lts [$($ty)* $($param)* ,] $lt_args $ty_args []
+            $($rest)*
+        }
+    );
+
+    (
+        @generics $item:tt $pub:tt $Name:ident
+        $lts:tt [$($ty:tt)*] $lt_args:tt $ty_args:tt [ty [] $($param:tt)*]
+        > $($rest:tt)*
+    ) => (
+        $crate::ඞnew_For_type! {
+            @generics $item $pub $Name
+            $lts [$($ty)* $($param)* ,] $lt_args $ty_args []
+            > $($rest)*
+        }
+    );
+
+    // Bounds of a parameter.
+    (
+        @generics $item:tt $pub:tt $Name:ident
+        $lts:tt $tys:tt $lt_args:tt $ty_args:tt
+        [$kind:ident [< $($depth:tt)*] $($param:tt)*]
+        >> $($rest:tt)*
+    ) => (
+        $crate::ඞnew_For_type! {
+            @generics $item $pub $Name
+            $lts $tys $lt_args $ty_args [$kind [$($depth)*] $($param)* >]
+            > $($rest)*
+        }
+    );
+
+    (
+        @generics $item:tt $pub:tt $Name:ident
+        $lts:tt $tys:tt $lt_args:tt $ty_args:tt
+        [$kind:ident [< $($depth:tt)*] $($param:tt)*]
+        > $($rest:tt)*
+    ) => (
+        $crate::ඞnew_For_type! {
+            @generics $item $pub $Name
+            $lts $tys $lt_args $ty_args [$kind [$($depth)*] $($param)* >]
+            $($rest)*
+        }
+    );
+
+    (
+        @generics $item:tt $pub:tt $Name:ident
+        $lts:tt $tys:tt $lt_args:tt $ty_args:tt
+        [$kind:ident [$($depth:tt)*] $($param:tt)*]
+        < $($rest:tt)*
+    ) => (
+        $crate::ඞnew_For_type! {
+            @generics $item $pub $Name
+            $lts $tys $lt_args $ty_args [$kind [< $($depth)*] $($param)* <]
+            $($rest)*
+        }
+    );
+
+    (
+        @generics $item:tt $pub:tt $Name:ident
+        $lts:tt $tys:tt $lt_args:tt $ty_args:tt
+        [$kind:ident $depth:tt $($param:tt)*]
+        $token:tt $($rest:tt)*
+    ) => (
+        $crate::ඞnew_For_type! {
+            @generics $item $pub $Name
+            $lts $tys $lt_args $ty_args [$kind $depth $($param)* $token]
+            $($rest)*
+        }
+    );
+
+    (
+        $( #$attr:tt )*
+        $pub:vis
+        type $Name:ident = $($rest:tt)*
+    ) => (
+        $crate::ඞnew_For_type! {
+            @emit[$(#$attr)*] $pub $Name [[] [] [] []] = $($rest)*
+        }
+    );
+
+    (
+        $( #$attr:tt )*
+        $pub:vis
+        type $Name:ident < $($rest:tt)*
+    ) => (
+        $crate::ඞnew_For_type! {
+            @generics[$(#$attr)*] $pub $Name [] [] [] [] []
+            $($rest)*
+        }
+    );
 }
 
 pub(crate)
 mod for_lt_and_lt {
+    #[cfg_attr(diagnostic_namespace, diagnostic::on_unimplemented(
+        message = "`{Self}` is not a `For!(<'a, 'b> = …)` type",
+        note = "`ForLtAndLt` cannot be implemented manually: use `For!(…)`, \
+                or `new_For_type!`",
+    ))]
     pub trait Sealed : Send + Sync + Unpin {}
 
     pub trait WithLifetimes<'a, 'b> {
@@ -505,6 +2565,11 @@ mod for_lt_and_lt {
         T : for<'a, 'b> WithLifetimes<'a, 'b>,
     {}
 
+    impl<T : ?Sized + crate::ඞ::Nominal> Sealed for T
+    where
+        Self : Send + Sync + Unpin,
+    {}
+
     impl<T : ?Sized>
         super::ForLtAndLt
     for
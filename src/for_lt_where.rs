@@ -0,0 +1,179 @@
+//! Named aliases for the higher-ranked `for<'a> T::Of<'a> : Bounds` clauses.
+//!
+//! A <code>\<T : [ForLt]\></code> API needing, say, to compare the `T::Of<'_>`
+//! has to spell out a `for<'a> T::Of<'a> : Ord` clause, which its own callers
+//! then have to repeat, since such a clause is not an implied bound.
+//!
+//! The traits herein, and the ones defined with [`new_ForLt_where!`], carry
+//! the clause as part of their supertraits instead: a <code>\<T : [ForLtOrd]\></code>
+//! lets the callee rely on `T::Of<'_> : Ord`, with nothing else to repeat.
+//!
+//! [ForLt]: trait@crate::ForLt
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ::higher_kinded_types::{ForLt, Hkt, hkt, for_lt_where::new_ForLt_where};
+//!
+//! new_ForLt_where! {
+//!     /// Every `T::Of<'a>` is a debuggable sort key.
+//!     pub trait ForLtKey = Ord + ::core::fmt::Debug;
+//! }
+//!
+//! fn slice_sort_by_key<Item, Key : ForLtKey> (
+//!     items: &'_ mut [Item],
+//!     _: Hkt<Key>,
+//!     mut get_key: impl for<'it> FnMut(&'it Item) -> Key::Of<'it>,
+//! )
+//! {
+//!     items.sort_by(|a: &'_ Item, b: &'_ Item| {
+//!         let (a, b) = (get_key(a), get_key(b));
+//!         println!("comparing {a:?} and {b:?}");
+//!         Ord::cmp(&a, &b)
+//!     })
+//! }
+//!
+//! /// No need to repeat any `for<'a> Key::Of<'a> : …` clause.
+//! fn sort_twice<Item, Key : ForLtKey> (
+//!     items: &'_ mut [Item],
+//!     key: Hkt<Key>,
+//!     mut get_key: impl for<'it> FnMut(&'it Item) -> Key::Of<'it>,
+//! )
+//! {
+//!     slice_sort_by_key(items, key, &mut get_key);
+//!     slice_sort_by_key(items, key, &mut get_key);
+//! }
+//!
+//! struct Client { key: String }
+//! let clients = &mut [Client { key: "b".into() }, Client { key: "a".into() }];
+//! sort_twice(clients, hkt!(&str), |c| &c.key);
+//! assert_eq!(clients[0].key, "a");
+//! ```
+//!
+//! ## Caveat
+//!
+//! These aliases do not compose: a `T : ForLtDebug + ForLtSend` is rejected,
+//! as ambiguous, by the compiler. Instead, a single [`new_ForLt_where!`] alias
+//! can be given several bounds at once, such as
+//! `pub trait ForLtDebugSend = Debug + Send;`.
+
+/// Defines [`ForLt`][ForLt]-based trait aliases with implied higher-ranked bounds:
+/// <code>trait ForLtAlias = Bounds;</code> stands for
+/// <code>[ForLt] + for\<\'a\> Of\<\'a\> : Bounds</code>.
+///
+/// [ForLt]: trait@crate::ForLt
+///
+/// Every [`ForLt`][ForLt] type meeting the bounds implements the alias.
+///
+/// See the [module docs][self] for an example.
+///
+/// Several `+`-separated bounds can be given to a single alias, all of which
+/// are then implied:
+///
+/// ```rust
+/// use ::higher_kinded_types::{ForLt, for_lt_where::new_ForLt_where};
+///
+/// new_ForLt_where! {
+///     pub trait ForLtToString = ::core::fmt::Display;
+///     trait ForLtCloneSend = Clone + Send;
+/// }
+///
+/// fn stringify<T : ForLtToString>(it: T::Of<'_>) -> String {
+///     it.to_string()
+/// }
+///
+/// /// Relies on both `T::Of<'_> : Clone` and `T::Of<'_> : Send`.
+/// fn send_copies<T : ForLtCloneSend>(it: T::Of<'_>) -> usize {
+///     ::std::thread::scope(|s| {
+///         let threads = [it.clone(), it].map(|copy| s.spawn(move || drop(copy)));
+///         threads.into_iter().map(|t| t.join().unwrap()).count()
+///     })
+/// }
+///
+/// assert_eq!(stringify::<ForLt!(&str)>("hi"), "hi");
+/// assert_eq!(send_copies::<ForLt!(&str)>("hi"), 2);
+/// ```
+#[macro_export] #[doc(hidden)]
+macro_rules! ඞnew_ForLt_where {
+    (@bounds $header:tt [$($Bounds:tt)*] ; $($rest:tt)*) => (
+        $crate::ඞnew_ForLt_where! { @emit $header $($Bounds)* }
+        $crate::ඞnew_ForLt_where! { $($rest)* }
+    );
+
+    (@bounds $header:tt [$($Bounds:tt)*] $tt:tt $($rest:tt)*) => (
+        $crate::ඞnew_ForLt_where! { @bounds $header [$($Bounds)* $tt] $($rest)* }
+    );
+
+    (
+        @emit [$(#$attr:tt)* $pub:vis $Name:ident]
+        $($Bounds:tt)*
+    ) => ($crate::ඞ::paste! {
+        $(#$attr)*
+        $pub
+        trait $Name
+        :
+            for<'ඞ /* ' */> [< $Name ඞOf >]<'ඞ /* ' */>
+            +
+            for<'ඞ /* ' */> $crate::ForLt<
+                Of<'ඞ /* ' */> = <Self as [< $Name ඞOf >]<'ඞ /* ' */>>::T,
+            >
+        {}
+
+        impl<ඞT : ?Sized + $crate::ForLt> $Name for ඞT
+        where
+            for<'ඞ /* ' */> <ඞT as $crate::ForLt>::Of<'ඞ /* ' */> : $($Bounds)*,
+        {}
+
+        /// Not part of the public API.
+        #[doc(hidden)]
+        $pub
+        trait [< $Name ඞOf >]<'ඞ /* ' */> {
+            type T : $($Bounds)*;
+        }
+
+        impl<'ඞ /* ' */, ඞT : ?Sized + $crate::ForLt> [< $Name ඞOf >]<'ඞ /* ' */> for ඞT
+        where
+            <ඞT as $crate::ForLt>::Of<'ඞ /* ' */> : $($Bounds)*,
+        {
+            type T = <ඞT as $crate::ForLt>::Of<'ඞ /* ' */>;
+        }
+    });
+
+    () => ();
+
+    (
+        $(#$attr:tt)*
+        $pub:vis trait $Name:ident = $($rest:tt)*
+    ) => (
+        $crate::ඞnew_ForLt_where! { @bounds [$(#$attr)* $pub $Name] [] $($rest)* }
+    );
+} #[doc(inline)] pub use ඞnew_ForLt_where as new_ForLt_where;
+
+macro_rules! for_lt_where {(
+    $( $Name:ident = $($path:ident)::+ ),* $(,)?
+) => (
+    $(
+        new_ForLt_where! {
+            #[doc = ::core::concat!(
+                "<code>[ForLt][trait@crate::ForLt] + for\\<\\'a\\> Of\\<\\'a\\> : ",
+                ::core::stringify!($($path)::+), "</code>.",
+            )]
+            pub trait $Name = ::$($path)::+;
+        }
+    )*
+)}
+
+for_lt_where! {
+    ForLtClone = core::clone::Clone,
+    ForLtCopy = core::marker::Copy,
+    ForLtDebug = core::fmt::Debug,
+    ForLtDefault = core::default::Default,
+    ForLtDisplay = core::fmt::Display,
+    ForLtEq = core::cmp::Eq,
+    ForLtHash = core::hash::Hash,
+    ForLtOrd = core::cmp::Ord,
+    ForLtPartialEq = core::cmp::PartialEq,
+    ForLtPartialOrd = core::cmp::PartialOrd,
+    ForLtSend = core::marker::Send,
+    ForLtSync = core::marker::Sync,
+}
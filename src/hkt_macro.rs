@@ -47,6 +47,49 @@ macro_rules! dispatch {($_:tt
     ///     # ;
     ///     ```
     ///
+    ///       - elided lifetimes inside `Fn…(…)`, `fn(…)` (including their
+    ///         return types) and `for<…>` are left alone, since these are
+    ///         higher-ranked ones: `ForLt!(Box<dyn Fn(&str) -> &str + '_>)`
+    ///         is `ForLt!(<'r> = Box<dyn for<'s> Fn(&'s str) -> &'s str + 'r>)`.
+    ///         An explicit `'_` in there, being ambiguous, is rejected.
+    ///
+    ///       - the type is to feature at least one such `'_` (or `&`): a type
+    ///         such as `Cow<str>` (for `Cow<'_, str>`), or `Context`, whose
+    ///         lifetime is hidden, is most likely a mistake, and is thus
+    ///         warned against (through a `deprecated` lint). For a type which
+    ///         really does not depend on the lifetime, use
+    ///         [`ForFixed`][crate::ForFixed] rather than, say, `ForLt!(u8)`:
+    ///
+    ///         ```rust ,compile_fail
+    ///         #![deny(deprecated)]
+    ///         # use ::higher_kinded_types::ForLt;
+    ///
+    ///         type Owned = ForLt!(u8); // Error, use `ForFixed<u8>` instead.
+    ///         ```
+    ///
+    ///       - with the `proc-macros` Cargo feature, the type is parsed by a
+    ///         proc-macro rather than by a `macro_rules!` "muncher", which
+    ///         does not hit the `recursion_limit` on big types, and reports
+    ///         the errors at the offending token.
+    ///
+    ///   - #### `?Sized` types
+    ///
+    ///     When the type is `?Sized`, such as `dyn Trait + '_`, `[&str]` or
+    ///     `str`, the produced type is a
+    ///     <code>[ForLtUnsized][crate::extra_arities::ForLtUnsized]</code>
+    ///     rather than a <code>[ForLt]</code>:
+    ///
+    ///     ```rust
+    ///     # use ::higher_kinded_types::ForLt;
+    ///     # #[cfg(not(feature = "fn_traits"))] let _:
+    ///     ForLt!(dyn ::core::fmt::Display + '_)
+    ///     # ;
+    ///     ```
+    ///
+    ///     This is not supported with the `fn_traits` Cargo feature, whose
+    ///     flavor of `ForLt!` produces `fn(…) -> Type` types, and thus only
+    ///     handles `Sized` ones (hence the example above being skipped then).
+    ///
     /// ### Examples
     ///
     /// ```rust
@@ -66,6 +109,12 @@ macro_rules! dispatch {($_:tt
     /// //     vvvvvvvvvvvvvvvvvvvvvvvvv
     /// let b: <B as ForLt>::Of<'static> = "b";
     /// let c: <C as ForLt>::Of<'static> = "c";
+    ///
+    /// // Elided lifetimes inside `Fn(…)` are left alone (higher-ranked).
+    /// type D = ForLt!(Box<dyn Fn(&str) -> &str + '_>);
+    /// fn id(s: &str) -> &str { s }
+    /// let d: <D as ForLt>::Of<'static> = Box::new(id);
+    /// assert_eq!(d(&String::from("d")), "d");
     /// ```
     #[macro_export]
     macro_rules! ForLt {
@@ -90,20 +139,16 @@ macro_rules! dispatch {($_:tt
             // For instance: `ForLt!(&str)` or `ForLt!(&'_ str)`.
             $_($shorthand_syntax:tt)*
         ) => (
-            $($($if_cfg_fn_traits)?
-                $_ crate::ඞ::ForLt<
-                    fn($_ crate::ඞ::r#for<'_>) -> $_($shorthand_syntax)*
-                >
-            )?
-            $($($if_not_cfg_fn_traits)?
-                $_ crate::ForLt! {
-                    <'ඞ /* ' */> = $_ crate::ඞForLt_munch! {
-                        [output: ]
-                        [input: $_($shorthand_syntax)*]
-                        [mode: default]
-                    }
+            $_ crate::ForLt! {
+                <'ඞ /* ' */> = $_ crate::ඞFor_munch! {
+                    [same: 'ඞ /* ' */]
+                    [used: ]
+                    [stack: ]
+                    [mode: default]
+                    [output: ]
+                    [input: $_($shorthand_syntax)*]
                 }
-            )?
+            }
         );
     }
 )}
@@ -1,7 +1,38 @@
+/// Munches the shorthand syntax of [`ForLt!`][crate::ForLt!] and of
+/// [`For!`][crate::extra_arities::For!], making the `'_` (and elided `&`)
+/// lifetimes explicit, given:
+///
+///   - either `[same: 'lt] [used: ]`, for every such lifetime to be `'lt`
+///     (`ForLt!`), which then gets `used` (at least one of them should be),
+///   - or `[fresh: 'a 'b …] [used: 'named…]`, for each such lifetime to be
+///     the next `fresh` one (`For!`), which then gets `used`.
+///
+/// Since the lifetimes introduced inside a `( … )` or `[ … ]` group matter to
+/// the ones after it, groups are munched in place, using an explicit `stack`
+/// of the enclosing groups.
+///
+/// The `mode` is:
+///
+///   - `default`: the one just described;
+///
+///   - `elided`: inside the `( … )` of `Fn…`/`fn`, wherein elided lifetimes
+///     are left alone, for they are higher-ranked ones;
+///
+///   - `scope[<…] $outer_mode`: the same, but for the return type of these,
+///     or the type/bound following a `for<…>`, up until a `+`, `,`, `;` or
+///     `>` at `<…>`-depth zero (hence the depth).
+///
+/// With the `proc-macros` feature, it is instead forwarded to a proc-macro,
+/// which properly parses the type.
+#[cfg(not(feature = "proc-macros"))]
 #[doc(hidden)] /** Not part of the public API */ #[macro_export]
-macro_rules! ඞForLt_munch {
+macro_rules! ඞFor_munch {
     // case `'_`
     (
+        [fresh: $fresh:lifetime $($fresh_rest:lifetime)*]
+        [used: $($used:lifetime)*]
+        $stack:tt
+        [mode: default]
         [output:
             $($acc:tt)*
         ]
@@ -9,20 +40,89 @@ macro_rules! ඞForLt_munch {
             '_
             $($rest:tt)*
         ]
-        $mode:tt
-    ) => ($crate::ඞForLt_munch! {
+    ) => ($crate::ඞFor_munch! {
+        [fresh: $($fresh_rest)*]
+        [used: $($used)* $fresh]
+        $stack
+        [mode: default]
         [output:
             $($acc)*
-            'ඞ /* ' */
+            $fresh
         ]
         [input:
             $($rest)*
         ]
-        $mode
     });
+    (
+        [same: $lifetime:lifetime]
+        $used:tt
+        $stack:tt
+        [mode: default]
+        [output:
+            $($acc:tt)*
+        ]
+        [input:
+            '_
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        [same: $lifetime]
+        [used: $lifetime]
+        $stack
+        [mode: default]
+        [output:
+            $($acc)*
+            $lifetime
+        ]
+        [input:
+            $($rest)*
+        ]
+    });
+
+    // case `'_`, with no `fresh` lifetimes left
+    (
+        [fresh: ]
+        $used:tt
+        $stack:tt
+        [mode: default]
+        $output:tt
+        [input:
+            '_
+            $($rest:tt)*
+        ]
+    ) => (
+        ::core::compile_error! {
+            "too many lifetimes in `For!` (up to 4 are supported)"
+        }
+    );
+
+    // case `'_` within `Fn(…)`, `fn(…)` or `for<…>`: it could be meant as
+    // either the higher-ranked lifetime, or the one of the `For` type.
+    (
+        $lifetimes:tt
+        $used:tt
+        $stack:tt
+        $mode:tt
+        $output:tt
+        [input:
+            '_
+            $($rest:tt)*
+        ]
+    ) => (
+        ::core::compile_error! { "\
+            ambiguous `'_` inside `Fn(…)`, `fn(…)` or `for<…>`: \
+            either elide it (e.g., `&str`), for a higher-ranked lifetime, \
+            or use the full `<'r> = …` syntax, for the lifetime of the `For` \
+            type\
+        "}
+    );
 
     // case `&'lifetime` (including `&'_`)
     (
+        $lifetimes:tt
+        $used:tt
+        $stack:tt
+        $mode:tt
         [output:
             $($acc:tt)*
         ]
@@ -31,8 +131,11 @@ macro_rules! ඞForLt_munch {
             $lifetime:lifetime
             $($rest:tt)*
         ]
-        $mode:tt
-    ) => ($crate::ඞForLt_munch! {
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        $stack
+        $mode
         [output:
             $($acc)*
             &
@@ -41,81 +144,458 @@ macro_rules! ඞForLt_munch {
             $lifetime
             $($rest)*
         ]
+    });
+
+    // case `&&` (a single token, to be split)
+    (
+        $lifetimes:tt
+        $used:tt
+        $stack:tt
+        $mode:tt
+        $output:tt
+        [input:
+            &&
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        $stack
         $mode
+        $output
+        [input:
+            & &
+            $($rest)*
+        ]
     });
 
     // case `& /* no lifetime */` (implicit elision)
     (
-        $acc:tt
+        $lifetimes:tt
+        $used:tt
+        $stack:tt
+        [mode: default]
+        $output:tt
         [input:
             &
             $($rest:tt)*
         ]
-        $mode:tt
-    ) => ($crate::ඞForLt_munch! {
-        $acc
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        $stack
+        [mode: default]
+        $output
         [input:
             // make it explicit
             &'_
             $($rest)*
         ]
-        $mode
     });
 
-    // case `(…)` (need to deep recurse)
+    // case `Fn…(…)` and `fn(…)`: elided lifetimes are left alone in there
+    // (push it onto the stack, in `elided` mode).
     (
+        $lifetimes:tt
+        $used:tt
+        [stack: $($frame:tt)*]
+        $mode:tt
         [output:
             $($acc:tt)*
         ]
         [input:
-            ( $($group:tt)* )
+            Fn ( $($args:tt)* )
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        [stack: [parenthesized [$($acc)* Fn] [$($rest)*] $mode] $($frame)*]
+        [mode: elided]
+        [output: ]
+        [input:
+            $($args)*
+        ]
+    });
+    (
+        $lifetimes:tt
+        $used:tt
+        [stack: $($frame:tt)*]
+        $mode:tt
+        [output:
+            $($acc:tt)*
+        ]
+        [input:
+            FnMut ( $($args:tt)* )
             $($rest:tt)*
         ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        [stack: [parenthesized [$($acc)* FnMut] [$($rest)*] $mode] $($frame)*]
+        [mode: elided]
+        [output: ]
+        [input:
+            $($args)*
+        ]
+    });
+    (
+        $lifetimes:tt
+        $used:tt
+        [stack: $($frame:tt)*]
         $mode:tt
-    ) => ($crate::ඞForLt_munch! {
+        [output:
+            $($acc:tt)*
+        ]
+        [input:
+            FnOnce ( $($args:tt)* )
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        [stack: [parenthesized [$($acc)* FnOnce] [$($rest)*] $mode] $($frame)*]
+        [mode: elided]
+        [output: ]
+        [input:
+            $($args)*
+        ]
+    });
+    (
+        $lifetimes:tt
+        $used:tt
+        [stack: $($frame:tt)*]
+        $mode:tt
+        [output:
+            $($acc:tt)*
+        ]
+        [input:
+            fn ( $($args:tt)* )
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        [stack: [parenthesized [$($acc)* fn] [$($rest)*] $mode] $($frame)*]
+        [mode: elided]
+        [output: ]
+        [input:
+            $($args)*
+        ]
+    });
+
+    // case `-> Ret` (of the above) and `for<…> Type`: these are left alone
+    // too, up until the end of the type (`scope` mode).
+    (
+        $lifetimes:tt
+        $used:tt
+        $stack:tt
+        [mode: $($mode:tt)*]
+        [output:
+            $($acc:tt)*
+        ]
+        [input:
+            ->
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        $stack
+        [mode: scope[] [$($mode)*]]
         [output:
             $($acc)*
-            $crate::ඞForLt_munch! {
-                [output: ]
-                [input: $($group)*]
-                [mode: parenthesized]
-            }
+            ->
         ]
         [input:
             $($rest)*
         ]
-        $mode
+    });
+    (
+        $lifetimes:tt
+        $used:tt
+        $stack:tt
+        [mode: $($mode:tt)*]
+        [output:
+            $($acc:tt)*
+        ]
+        [input:
+            for < $($lt:lifetime),* $(,)? >
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        $stack
+        [mode: scope[] [$($mode)*]]
+        [output:
+            $($acc)*
+            for<$($lt),*>
+        ]
+        [input:
+            $($rest)*
+        ]
+    });
+
+    // `scope` mode: end of the type (back to the outer mode).
+    (
+        $lifetimes:tt
+        $used:tt
+        $stack:tt
+        [mode: scope[] [$($outer:tt)*]]
+        $output:tt
+        [input:
+            +
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        $stack
+        [mode: $($outer)*]
+        $output
+        [input:
+            +
+            $($rest)*
+        ]
+    });
+    (
+        $lifetimes:tt
+        $used:tt
+        $stack:tt
+        [mode: scope[] [$($outer:tt)*]]
+        $output:tt
+        [input:
+            ,
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        $stack
+        [mode: $($outer)*]
+        $output
+        [input:
+            ,
+            $($rest)*
+        ]
+    });
+    (
+        $lifetimes:tt
+        $used:tt
+        $stack:tt
+        [mode: scope[] [$($outer:tt)*]]
+        $output:tt
+        [input:
+            ;
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        $stack
+        [mode: $($outer)*]
+        $output
+        [input:
+            ;
+            $($rest)*
+        ]
+    });
+    (
+        $lifetimes:tt
+        $used:tt
+        $stack:tt
+        [mode: scope[] [$($outer:tt)*]]
+        $output:tt
+        [input:
+            >
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        $stack
+        [mode: $($outer)*]
+        $output
+        [input:
+            >
+            $($rest)*
+        ]
     });
 
-    // case `[…]` (need to deep recurse)
+    // `scope` mode: `<…>`-depth tracking.
+    (
+        $lifetimes:tt
+        $used:tt
+        $stack:tt
+        [mode: scope $depth:tt $outer:tt]
+        $output:tt
+        [input:
+            >>
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        $stack
+        [mode: scope $depth $outer]
+        $output
+        [input:
+            > >
+            $($rest)*
+        ]
+    });
     (
+        $lifetimes:tt
+        $used:tt
+        $stack:tt
+        [mode: scope[$($depth:tt)*] $outer:tt]
         [output:
             $($acc:tt)*
         ]
         [input:
-            [ $($group:tt)* ]
+            <
             $($rest:tt)*
         ]
-        $mode:tt
-    ) => ($crate::ඞForLt_munch! {
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        $stack
+        [mode: scope[< $($depth)*] $outer]
         [output:
             $($acc)*
-            $crate::ඞForLt_munch! {
-                [output: ]
-                [input: $($group)*]
-                [mode: square_bracketed]
-            }
+            <
         ]
         [input:
             $($rest)*
         ]
-        $mode
+    });
+    (
+        $lifetimes:tt
+        $used:tt
+        $stack:tt
+        [mode: scope[< $($depth:tt)*] $outer:tt]
+        [output:
+            $($acc:tt)*
+        ]
+        [input:
+            >
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        $stack
+        [mode: scope[$($depth)*] $outer]
+        [output:
+            $($acc)*
+            >
+        ]
+        [input:
+            $($rest)*
+        ]
+    });
+
+    // case `(…)` (push it onto the stack)
+    (
+        $lifetimes:tt
+        $used:tt
+        [stack: $($frame:tt)*]
+        [mode: default]
+        [output:
+            $($acc:tt)*
+        ]
+        [input:
+            ( $($group:tt)* )
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        [stack: [parenthesized [$($acc)*] [$($rest)*] [mode: default]] $($frame)*]
+        [mode: default]
+        [output: ]
+        [input:
+            $($group)*
+        ]
+    });
+    (
+        $lifetimes:tt
+        $used:tt
+        [stack: $($frame:tt)*]
+        $mode:tt
+        [output:
+            $($acc:tt)*
+        ]
+        [input:
+            ( $($group:tt)* )
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        [stack: [parenthesized [$($acc)*] [$($rest)*] $mode] $($frame)*]
+        [mode: elided]
+        [output: ]
+        [input:
+            $($group)*
+        ]
+    });
+
+    // case `[…]` (push it onto the stack)
+    (
+        $lifetimes:tt
+        $used:tt
+        [stack: $($frame:tt)*]
+        [mode: default]
+        [output:
+            $($acc:tt)*
+        ]
+        [input:
+            [ $($group:tt)* ]
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        [stack: [square_bracketed [$($acc)*] [$($rest)*] [mode: default]] $($frame)*]
+        [mode: default]
+        [output: ]
+        [input:
+            $($group)*
+        ]
+    });
+    (
+        $lifetimes:tt
+        $used:tt
+        [stack: $($frame:tt)*]
+        $mode:tt
+        [output:
+            $($acc:tt)*
+        ]
+        [input:
+            [ $($group:tt)* ]
+            $($rest:tt)*
+        ]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        [stack: [square_bracketed [$($acc)*] [$($rest)*] $mode] $($frame)*]
+        [mode: elided]
+        [output: ]
+        [input:
+            $($group)*
+        ]
     });
 
     /* No need to recurse into `{ … }`, so we handle it with the default tt */
 
     // Otherwise / default `tt` case: just forward it, _verbatim_
     (
+        $lifetimes:tt
+        $used:tt
+        $stack:tt
+        $mode:tt
         [output:
             $($acc:tt)*
         ]
@@ -123,8 +603,11 @@ macro_rules! ඞForLt_munch {
             $otherwise:tt
             $($rest:tt)*
         ]
-        $mode:tt
-    ) => ($crate::ඞForLt_munch! {
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        $stack
+        $mode
         [output:
             $($acc)*
             $otherwise
@@ -132,30 +615,100 @@ macro_rules! ඞForLt_munch {
         [input:
             $($rest)*
         ]
+    });
+
+    // End of a group: pop it off the stack (back to its outer mode).
+    (
+        $lifetimes:tt
+        $used:tt
+        [stack:
+            [parenthesized [$($acc:tt)*] [$($rest:tt)*] $mode:tt]
+            $($frame:tt)*
+        ]
+        $_:tt
+        [output: $($output:tt)*]
+        [input: /* nothing left! */]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        [stack: $($frame)*]
+        $mode
+        [output:
+            $($acc)*
+            ( $($output)* )
+        ]
+        [input:
+            $($rest)*
+        ]
+    });
+    (
+        $lifetimes:tt
+        $used:tt
+        [stack:
+            [square_bracketed [$($acc:tt)*] [$($rest:tt)*] $mode:tt]
+            $($frame:tt)*
+        ]
+        $_:tt
+        [output: $($output:tt)*]
+        [input: /* nothing left! */]
+    ) => ($crate::ඞFor_munch! {
+        $lifetimes
+        $used
+        [stack: $($frame)*]
         $mode
+        [output:
+            $($acc)*
+            [ $($output)* ]
+        ]
+        [input:
+            $($rest)*
+        ]
     });
 
     /* END OF RECURSION */
     (
+        [same: $lifetime:lifetime]
+        [used: $used:lifetime]
+        [stack: ]
+        $mode:tt
         [output: $Output:ty $(,)? ]
         [input: /* nothing left! */]
-        [mode: default]
     ) => (
         $Output
     );
-    // Also handle the grouping modes:
+    // No `'_` (nor elided `&`) whatsoever: the lifetime is unused, which is
+    // most likely a mistake, _e.g._, `Cow<str>`, with a hidden elided lifetime.
     (
+        [same: $lifetime:lifetime]
+        [used: ]
+        [stack: ]
+        $mode:tt
         [output: $($output:tt)*]
         [input: /* nothing left! */]
-        [mode: parenthesized]
     ) => (
-        ( $($output)* )
+        $crate::ඞ::ForLtWithoutLifetime<$($output)*>
     );
     (
+        [fresh: $($fresh:lifetime)*]
+        [used: $($used:lifetime)*]
+        [stack: ]
+        $mode:tt
         [output: $($output:tt)*]
         [input: /* nothing left! */]
-        [mode: square_bracketed]
     ) => (
-        [ $($output)* ]
+        $crate::ඞFor! {
+            @shorthand[$($used)*] $($output)*
+        }
     );
 }
+
+#[cfg(feature = "proc-macros")]
+#[doc(hidden)] /** Not part of the public API */ #[macro_export]
+macro_rules! ඞFor_munch {(
+    $($input:tt)*
+) => (
+    $crate::ඞ::For_munch! {
+        [crate: $crate]
+        $($input)*
+    }
+)}
@@ -0,0 +1,201 @@
+//! A first-class [`LendingIterator`] trait, built on top of [`ForLt`].
+//!
+//! [`ForLt`]: trait@ForLt
+//!
+//! This is the "real code" version of the `LendingIterator` sketched in the
+//! documentation of [`ForLifetime`][crate::ForLifetime]: the `Item` of such an
+//! iterator is not a type, but a `: <'_>`-generic type, _i.e._, an
+//! <code>impl [ForLt]</code> type, which is then fed the lifetime of the
+//! `&'_ mut self` borrow in [`.next()`][LendingIterator::next].
+//!
+//! This lets an iterator hand out items borrowing from _itself_ (_e.g._, from
+//! some internal buffer), at the cost of not being able to hold two such items
+//! simultaneously.
+//!
+//! [ForLt]: trait@ForLt
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ::higher_kinded_types::{ForLt, lending_iterator::LendingIterator};
+//!
+//! /// Yields `"0"`, `"01"`, `"012"`, … out of one and the same `String`.
+//! struct Prefixes {
+//!     buf: String,
+//!     remaining: u8,
+//! }
+//!
+//! impl LendingIterator for Prefixes {
+//!     type Item = ForLt!(&str);
+//!
+//!     fn next(&mut self) -> Option<&'_ str> {
+//!         self.remaining = self.remaining.checked_sub(1)?;
+//!         let digit = char::from(b'0' + self.buf.len() as u8);
+//!         self.buf.push(digit);
+//!         Some(&self.buf)
+//!     }
+//! }
+//!
+//! let prefixes = || Prefixes { buf: String::new(), remaining: 5 };
+//!
+//! let mut seen = vec![];
+//! prefixes()
+//!     .skip(1)
+//!     .filter(|s: &&str| s.len() % 2 == 0)
+//!     .map::<ForLt!((usize, &str)), _>(|[], s| (s.len(), s))
+//!     .for_each(|(len, s)| seen.push(format!("{len}: {s}")))
+//! ;
+//! assert_eq!(seen, ["2: 01", "4: 0123"]);
+//!
+//! let total_len = prefixes().take(3).fold(0, |acc, s| acc + s.len());
+//! assert_eq!(total_len, 1 + 2 + 3);
+//! ```
+
+use crate::ForLt;
+
+pub use adapters::{Filter, Map, Skip, Take};
+mod adapters;
+
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "docs-rs",
+    doc(cfg(feature = "std")),
+)]
+pub
+mod io;
+
+/// Convenience alias to name the type of the items of some
+/// <code>I : [LendingIterator]</code> when lent for `'lt`.
+pub
+type Item<'lt, I : ?Sized + LendingIterator> =
+    <<I as LendingIterator>::Item as ForLt>::Of<'lt>
+;
+
+/// An iterator whose items may borrow from the iterator itself.
+///
+/// See the [module docs][self] for more info.
+///
+/// ## Adapters
+///
+/// The usual [`Iterator`] adapters are available, with the following caveats:
+///
+///   - [`.map()`][Self::map] cannot infer the `: <'_>`-generic type of its
+///     output (see the [`ForLt` docs][crate::ForLifetime] for the why), so it
+///     has to be turbofished: `.map::<ForLt!(…), _>(|[], item| …)`;
+///
+///       - the `[]` dummy parameter is there to carry the lifetime of the
+///         `item`, since Rust does not let a `for<'n>`-quantified lifetime
+///         appear only in the output and in an associated type such as
+///         `Item<'n, Self>`;
+///
+///   - there is no `.peekable()`: the peeked item would be borrowing from the
+///     very iterator which the adapter would still be holding onto, and
+///     advancing, a self-referential pattern which cannot be expressed without
+///     `unsafe`.
+pub
+trait LendingIterator {
+    /// The `: <'_>`-generic type of the items being lent.
+    type Item : ForLt;
+
+    /// Advances the iterator and returns the next item, if any, lent from
+    /// `*self` (thus, for the duration of the `&mut` borrow).
+    fn next(&mut self)
+      -> Option<Item<'_, Self>>
+    ;
+
+    /// Lazily applies `f` to each item, with `f` yielding a `U::Of<'_>`.
+    ///
+    /// The first `[]` parameter of `f` is a dummy one, needed for technical
+    /// reasons (see [above][Self#adapters]).
+    ///
+    /// ```rust
+    /// use ::higher_kinded_types::{ForLt, lending_iterator::LendingIterator};
+    ///
+    /// fn first_bytes<I>(iter: I) -> impl LendingIterator<Item = ForLt!(&[u8])>
+    /// where
+    ///     I : LendingIterator<Item = ForLt!(&mut String)>,
+    /// {
+    ///     iter.map::<ForLt!(&[u8]), _>(|[], s: &mut String| &s.as_bytes()[.. 1])
+    /// }
+    /// ```
+    fn map<U : ForLt, F>(self, f: F)
+      -> Map<Self, F, U>
+    where
+        Self : Sized,
+        F : for<'n> FnMut([&'n (); 0], Item<'n, Self>) -> U::Of<'n>,
+    {
+        Map::new(self, f)
+    }
+
+    /// Lazily skips the items for which `predicate` returns `false`.
+    fn filter<F>(self, predicate: F)
+      -> Filter<Self, F>
+    where
+        Self : Sized,
+        F : FnMut(&Item<'_, Self>) -> bool,
+    {
+        Filter::new(self, predicate)
+    }
+
+    /// Yields (at most) the first `n` items.
+    fn take(self, n: usize)
+      -> Take<Self>
+    where
+        Self : Sized,
+    {
+        Take::new(self, n)
+    }
+
+    /// Skips the first `n` items.
+    fn skip(self, n: usize)
+      -> Skip<Self>
+    where
+        Self : Sized,
+    {
+        Skip::new(self, n)
+    }
+
+    /// Borrows the iterator rather than consuming it, so that it can still be
+    /// used after having been fed to some adapter.
+    fn by_ref(&mut self)
+      -> &mut Self
+    {
+        self
+    }
+
+    /// Calls `f` on each item.
+    fn for_each(mut self, mut f: impl FnMut(Item<'_, Self>))
+    where
+        Self : Sized,
+    {
+        while let Some(item) = self.next() {
+            f(item);
+        }
+    }
+
+    /// Folds every item into an accumulator, by applying `f`.
+    fn fold<Acc>(
+        mut self,
+        init: Acc,
+        mut f: impl FnMut(Acc, Item<'_, Self>) -> Acc,
+    ) -> Acc
+    where
+        Self : Sized,
+    {
+        let mut acc = init;
+        while let Some(item) = self.next() {
+            acc = f(acc, item);
+        }
+        acc
+    }
+}
+
+impl<I : ?Sized + LendingIterator> LendingIterator for &'_ mut I {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self)
+      -> Option<Item<'_, I>>
+    {
+        I::next(self)
+    }
+}
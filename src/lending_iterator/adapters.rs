@@ -0,0 +1,146 @@
+use {
+    ::core::marker::PhantomData,
+    super::{
+        Item,
+        LendingIterator,
+    },
+    crate::ForLt,
+};
+
+/// The [`LendingIterator`] returned by [`.map()`][LendingIterator::map].
+pub
+struct Map<I, F, U : ForLt> {
+    iter: I,
+    f: F,
+    _output: PhantomData<fn() -> U>,
+}
+
+impl<I, F, U : ForLt> Map<I, F, U> {
+    pub(super)
+    fn new(iter: I, f: F)
+      -> Self
+    {
+        Self { iter, f, _output: PhantomData }
+    }
+}
+
+impl<I, F, U : ForLt> LendingIterator for Map<I, F, U>
+where
+    I : LendingIterator,
+    F : for<'n> FnMut([&'n (); 0], Item<'n, I>) -> U::Of<'n>,
+{
+    type Item = U;
+
+    #[inline]
+    fn next(&mut self)
+      -> Option<U::Of<'_>>
+    {
+        self.iter.next().map(|item| (self.f)([], item))
+    }
+}
+
+/// The [`LendingIterator`] returned by [`.filter()`][LendingIterator::filter].
+pub
+struct Filter<I, F> {
+    iter: I,
+    predicate: F,
+}
+
+impl<I, F> Filter<I, F> {
+    pub(super)
+    fn new(iter: I, predicate: F)
+      -> Self
+    {
+        Self { iter, predicate }
+    }
+}
+
+impl<I, F> LendingIterator for Filter<I, F>
+where
+    I : LendingIterator,
+    F : FnMut(&Item<'_, I>) -> bool,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self)
+      -> Option<Item<'_, I>>
+    {
+        let mut iter = &mut self.iter;
+        // `loop { match iter.next() { Some(it) if !predicate(&it) => continue,
+        // ret => return ret } }`, but accepted by NLL (it is problem case #3:
+        // conditionally returning a borrow).
+        loop {
+            match ::polonius_the_crab::polonius::<
+                dyn for<'n> ::polonius_the_crab::WithLifetime<'n, T = Option<Item<'n, I>>>,
+                _, _, _,
+            >(iter, |iter| match iter.next() {
+                | Some(item) if !(self.predicate)(&item) => Err(()),
+                | ret => Ok(ret),
+            })
+            {
+                | Ok(ret) => return ret,
+                | Err((it, ())) => iter = it,
+            }
+        }
+    }
+}
+
+/// The [`LendingIterator`] returned by [`.take()`][LendingIterator::take].
+pub
+struct Take<I> {
+    iter: I,
+    remaining: usize,
+}
+
+impl<I> Take<I> {
+    pub(super)
+    fn new(iter: I, n: usize)
+      -> Self
+    {
+        Self { iter, remaining: n }
+    }
+}
+
+impl<I : LendingIterator> LendingIterator for Take<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self)
+      -> Option<Item<'_, I>>
+    {
+        self.remaining = self.remaining.checked_sub(1)?;
+        self.iter.next()
+    }
+}
+
+/// The [`LendingIterator`] returned by [`.skip()`][LendingIterator::skip].
+pub
+struct Skip<I> {
+    iter: I,
+    to_skip: usize,
+}
+
+impl<I> Skip<I> {
+    pub(super)
+    fn new(iter: I, n: usize)
+      -> Self
+    {
+        Self { iter, to_skip: n }
+    }
+}
+
+impl<I : LendingIterator> LendingIterator for Skip<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self)
+      -> Option<Item<'_, I>>
+    {
+        while self.to_skip > 0 {
+            self.to_skip -= 1;
+            self.iter.next()?;
+        }
+        self.iter.next()
+    }
+}
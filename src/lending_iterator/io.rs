@@ -0,0 +1,216 @@
+//! [`LendingIterator`] readers which lend each line or record out of one and
+//! the same buffer, thereby not allocating per item (contrary to, say,
+//! [`BufRead::lines()`]).
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ::higher_kinded_types::lending_iterator::{io, LendingIterator};
+//!
+//! let log: &[u8] = b"INFO boot\nWARN disk\r\nINFO ready\n";
+//!
+//! let mut warnings = 0;
+//! io::lines(log).for_each(|line| {
+//!     if line.unwrap().starts_with("WARN") {
+//!         warnings += 1;
+//!     }
+//! });
+//! assert_eq!(warnings, 1);
+//! ```
+
+use {
+    ::std::{
+        io::{self, BufRead, Read},
+        string::String,
+        vec::Vec,
+    },
+    super::LendingIterator,
+    crate::ForLt,
+};
+
+/// Lends the lines of `reader`, without their trailing `\n` or `\r\n`, out of
+/// a reused [`String`] buffer.
+///
+/// The lending counterpart of [`BufRead::lines()`].
+pub
+fn lines<R : BufRead>(reader: R)
+  -> Lines<R>
+{
+    Lines { reader, buf: String::new() }
+}
+
+/// The [`LendingIterator`] returned by [`lines()`].
+pub
+struct Lines<R> {
+    reader: R,
+    buf: String,
+}
+
+impl<R : BufRead> LendingIterator for Lines<R> {
+    type Item = ForLt!(io::Result<&str>);
+
+    fn next(&mut self)
+      -> Option<io::Result<&'_ str>>
+    {
+        self.buf.clear();
+        match self.reader.read_line(&mut self.buf) {
+            | Ok(0) => None,
+            | Ok(_) => {
+                let line = self.buf.strip_suffix('\n').unwrap_or(&self.buf);
+                Some(Ok(line.strip_suffix('\r').unwrap_or(line)))
+            },
+            | Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+/// Lends the `delimiter`-separated records of `reader`, without the trailing
+/// `delimiter`, out of a reused [`Vec`] buffer.
+///
+/// The lending counterpart of [`BufRead::split()`].
+///
+/// ## Example
+///
+/// ```rust
+/// use ::higher_kinded_types::lending_iterator::{io, LendingIterator};
+///
+/// let csv_row: &[u8] = b"1,22,333";
+/// let lens = io::split(csv_row, b',').fold(vec![], |mut lens, field| {
+///     lens.push(field.unwrap().len());
+///     lens
+/// });
+/// assert_eq!(lens, [1, 2, 3]);
+/// ```
+pub
+fn split<R : BufRead>(reader: R, delimiter: u8)
+  -> Split<R>
+{
+    Split { reader, delimiter, buf: Vec::new() }
+}
+
+/// The [`LendingIterator`] returned by [`split()`].
+pub
+struct Split<R> {
+    reader: R,
+    delimiter: u8,
+    buf: Vec<u8>,
+}
+
+impl<R : BufRead> LendingIterator for Split<R> {
+    type Item = ForLt!(io::Result<&[u8]>);
+
+    fn next(&mut self)
+      -> Option<io::Result<&'_ [u8]>>
+    {
+        self.buf.clear();
+        match self.reader.read_until(self.delimiter, &mut self.buf) {
+            | Ok(0) => None,
+            | Ok(_) => Some(Ok(
+                self.buf.strip_suffix(&[self.delimiter]).unwrap_or(&self.buf)
+            )),
+            | Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+/// Lends the frames of `reader`, each of which is expected to be prefixed by
+/// its length, as a big-endian `u32`, out of a reused [`Vec`] buffer.
+///
+/// Reaching EOF in between frames ends the iteration, whereas reaching it in
+/// the middle of one yields an [`io::ErrorKind::UnexpectedEof`] error.
+///
+/// The buffer only grows with the bytes actually read, so that a bogus length
+/// does not cause a 4 GiB allocation upfront. Still, an untrusted `reader` is
+/// better capped with [`.max_frame_len()`][LengthPrefixed::max_frame_len].
+///
+/// ## Example
+///
+/// ```rust
+/// use ::higher_kinded_types::lending_iterator::{io, LendingIterator};
+///
+/// let stream: &[u8] = b"\0\0\0\x05hello\0\0\0\0\0\0\0\x01!";
+/// let mut frames = io::length_prefixed(stream);
+/// assert_eq!(frames.next().unwrap().unwrap(), b"hello");
+/// assert_eq!(frames.next().unwrap().unwrap(), b"");
+/// assert_eq!(frames.next().unwrap().unwrap(), b"!");
+/// assert!(frames.next().is_none());
+///
+/// let truncated: &[u8] = b"\0\0\0\x05hel";
+/// let err = io::length_prefixed(truncated).next().unwrap().unwrap_err();
+/// assert_eq!(err.kind(), ::std::io::ErrorKind::UnexpectedEof);
+///
+/// // A (claimed) 4 GiB frame, with but a few bytes of payload.
+/// let bogus: &[u8] = b"\xff\xff\xff\xffhi";
+/// let err = io::length_prefixed(bogus).next().unwrap().unwrap_err();
+/// assert_eq!(err.kind(), ::std::io::ErrorKind::UnexpectedEof);
+/// ```
+pub
+fn length_prefixed<R : Read>(reader: R)
+  -> LengthPrefixed<R>
+{
+    LengthPrefixed { reader, max_frame_len: u32::MAX, buf: Vec::new() }
+}
+
+/// The [`LendingIterator`] returned by [`length_prefixed()`].
+pub
+struct LengthPrefixed<R> {
+    reader: R,
+    max_frame_len: u32,
+    buf: Vec<u8>,
+}
+
+impl<R> LengthPrefixed<R> {
+    /// Rejects the frames longer than `max` bytes, with an
+    /// [`io::ErrorKind::InvalidData`] error, before reading their payload
+    /// (the `reader` is then left in the middle of the stream, so the
+    /// iteration is not meant to go on).
+    ///
+    /// ```rust
+    /// use ::higher_kinded_types::lending_iterator::{io, LendingIterator};
+    ///
+    /// let stream: &[u8] = b"\0\0\0\x02hi\0\0\x01\0";
+    /// let mut frames = io::length_prefixed(stream).max_frame_len(255);
+    /// assert_eq!(frames.next().unwrap().unwrap(), b"hi");
+    /// let err = frames.next().unwrap().unwrap_err();
+    /// assert_eq!(err.kind(), ::std::io::ErrorKind::InvalidData);
+    /// ```
+    pub
+    fn max_frame_len(self, max: u32)
+      -> Self
+    {
+        Self { max_frame_len: max, ..self }
+    }
+}
+
+impl<R : Read> LendingIterator for LengthPrefixed<R> {
+    type Item = ForLt!(io::Result<&[u8]>);
+
+    fn next(&mut self)
+      -> Option<io::Result<&'_ [u8]>>
+    {
+        let mut len = [0; 4];
+        let mut filled = 0;
+        while filled < len.len() {
+            match self.reader.read(&mut len[filled ..]) {
+                | Ok(0) if filled == 0 => return None,
+                | Ok(0) => return Some(Err(io::ErrorKind::UnexpectedEof.into())),
+                | Ok(n) => filled += n,
+                | Err(err) if err.kind() == io::ErrorKind::Interrupted => {},
+                | Err(err) => return Some(Err(err)),
+            }
+        }
+        let len = u32::from_be_bytes(len);
+        if len > self.max_frame_len {
+            return Some(Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "length-prefixed frame exceeding the `max_frame_len()`",
+            )));
+        }
+        self.buf.clear();
+        match (&mut self.reader).take(len.into()).read_to_end(&mut self.buf) {
+            | Ok(n) if n as u64 == u64::from(len) => Some(Ok(&self.buf)),
+            | Ok(_) => Some(Err(io::ErrorKind::UnexpectedEof.into())),
+            | Err(err) => Some(Err(err)),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! The `async` counterpart of a [`LendingIterator`]: a [`LendingStream`].
+//!
+//! [`LendingIterator`]: crate::lending_iterator::LendingIterator
+//!
+//! That is, a `Stream` whose items, much like a `Future`'s `Context<'_>`
+//! (_c.f._ the `RefMutContext` example of [`ForLtAndLt`]), are lent for the
+//! duration of the `Pin<&'_ mut Self>` borrow of each
+//! [`.poll_next()`][LendingStream::poll_next] call: the items may thus borrow
+//! from the stream itself, _e.g._, from some internal buffer.
+//!
+//! [`ForLtAndLt`]: crate::extra_arities::ForLtAndLt
+//!
+//! ## Example
+//!
+//! ```rust
+//! use {
+//!     ::core::{pin::Pin, task::{Context, Poll}},
+//!     ::higher_kinded_types::{ForLt, lending_stream::LendingStream},
+//! };
+//!
+//! /// Decodes `u8`-length-prefixed frames, lending them out of its buffer.
+//! struct Frames<'input> {
+//!     input: &'input [u8],
+//!     buf: Vec<u8>,
+//! }
+//!
+//! impl LendingStream for Frames<'_> {
+//!     type Item = ForLt!(&[u8]);
+//!
+//!     fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>)
+//!       -> Poll<Option<&'_ [u8]>>
+//!     {
+//!         let this = self.get_mut();
+//!         let Some((&len, rest)) = this.input.split_first() else {
+//!             return Poll::Ready(None);
+//!         };
+//!         let (frame, rest) = rest.split_at(len.into());
+//!         this.input = rest;
+//!         this.buf.clear();
+//!         this.buf.extend_from_slice(frame);
+//!         Poll::Ready(Some(&this.buf))
+//!     }
+//! }
+//!
+//! async fn total_len(mut frames: Frames<'_>) -> usize {
+//!     let mut total = 0;
+//!     while let Some(frame) = frames.next().await {
+//!         total += frame.len();
+//!     }
+//!     total
+//! }
+//!
+//! async fn non_empty_firsts(frames: Frames<'_>) -> Vec<u8> {
+//!     let mut firsts = vec![];
+//!     frames
+//!         .filter(|frame: &&[u8]| !frame.is_empty())
+//!         .map::<ForLt!(&u8), _>(|[], frame| &frame[0])
+//!         .for_each(|&first| firsts.push(first))
+//!         .await
+//!     ;
+//!     firsts
+//! }
+//!
+//! let frames = || Frames { input: b"\x02ab\x00\x01c", buf: vec![] };
+//! assert_eq!(block_on(total_len(frames())), 3);
+//! assert_eq!(block_on(non_empty_firsts(frames())), b"ac");
+//! # fn block_on<F : ::core::future::Future>(fut: F) -> F::Output {
+//! #     use ::std::{sync::Arc, task::{Poll, Wake}};
+//! #     struct NoopWaker; impl Wake for NoopWaker { fn wake(self: Arc<Self>) {} }
+//! #     let waker = Arc::new(NoopWaker).into();
+//! #     let mut fut = Box::pin(fut);
+//! #     loop {
+//! #         if let Poll::Ready(it) = fut.as_mut().poll(&mut Context::from_waker(&waker)) {
+//! #             return it;
+//! #         }
+//! #     }
+//! # }
+//! ```
+
+use {
+    ::core::{
+        ops::DerefMut,
+        pin::Pin,
+        task::{Context, Poll},
+    },
+    crate::ForLt,
+};
+
+pub use adapters::{Filter, ForEach, Map, Next};
+mod adapters;
+
+/// Convenience alias to name the type of the items of some
+/// <code>S : [LendingStream]</code> when lent for `'lt`.
+pub
+type Item<'lt, S : ?Sized + LendingStream> =
+    <<S as LendingStream>::Item as ForLt>::Of<'lt>
+;
+
+/// A `Stream` whose items may borrow from the stream itself.
+///
+/// See the [module docs][self] for more info.
+///
+/// The provided combinators only require `Self : Unpin`, or `Self : Sized`:
+/// a `!Unpin` stream can be [`pin!`]ned first, since `Pin<&mut S>` is itself a
+/// [`LendingStream`].
+///
+/// [`pin!`]: https://doc.rust-lang.org/1.68.0/core/pin/macro.pin.html
+pub
+trait LendingStream {
+    /// The `: <'_>`-generic type of the items being lent.
+    type Item : ForLt;
+
+    /// Attempts to pull out the next item of the stream, lent from `*self`
+    /// (thus, for the duration of the `Pin<&mut>` borrow).
+    ///
+    /// Same semantics as `futures::Stream::poll_next()`.
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>)
+      -> Poll<Option<Item<'_, Self>>>
+    ;
+
+    /// `async fn next(&mut self) -> Option<Item<'_, Self>>`.
+    fn next(&mut self)
+      -> Next<'_, Self>
+    where
+        Self : Unpin,
+    {
+        Next::new(self)
+    }
+
+    /// Lazily applies `f` to each item, with `f` yielding a `U::Of<'_>`.
+    ///
+    /// Same caveats as for [`LendingIterator::map()`][
+    /// crate::lending_iterator::LendingIterator::map], such as the dummy `[]`
+    /// parameter.
+    fn map<U : ForLt, F>(self, f: F)
+      -> Map<Self, F, U>
+    where
+        Self : Sized,
+        F : for<'n> FnMut([&'n (); 0], Item<'n, Self>) -> U::Of<'n>,
+    {
+        Map::new(self, f)
+    }
+
+    /// Lazily skips the items for which `predicate` returns `false`.
+    fn filter<F>(self, predicate: F)
+      -> Filter<Self, F>
+    where
+        Self : Sized,
+        F : FnMut(&Item<'_, Self>) -> bool,
+    {
+        Filter::new(self, predicate)
+    }
+
+    /// A future calling `f` on each item, and resolving once the stream is
+    /// exhausted.
+    fn for_each<F>(self, f: F)
+      -> ForEach<Self, F>
+    where
+        Self : Sized,
+        F : FnMut(Item<'_, Self>),
+    {
+        ForEach::new(self, f)
+    }
+}
+
+impl<S : ?Sized + LendingStream + Unpin> LendingStream for &'_ mut S {
+    type Item = S::Item;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>)
+      -> Poll<Option<Item<'_, S>>>
+    {
+        S::poll_next(Pin::new(self.get_mut()), cx)
+    }
+}
+
+impl<P> LendingStream for Pin<P>
+where
+    P : DerefMut + Unpin,
+    P::Target : LendingStream,
+{
+    type Item = <P::Target as LendingStream>::Item;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>)
+      -> Poll<Option<Item<'_, P::Target>>>
+    {
+        self.get_mut().as_mut().poll_next(cx)
+    }
+}
@@ -0,0 +1,183 @@
+use {
+    ::core::{
+        future::Future,
+        marker::PhantomData,
+        pin::Pin,
+        task::{Context, Poll},
+    },
+    super::{
+        Item,
+        LendingStream,
+    },
+    ::polonius_the_crab::{
+        polonius,
+        WithLifetime,
+    },
+    crate::ForLt,
+};
+
+/// The [`Future`] returned by [`.next()`][LendingStream::next].
+pub
+struct Next<'stream, S : ?Sized> {
+    stream: Option<&'stream mut S>,
+}
+
+impl<'stream, S : ?Sized> Next<'stream, S> {
+    pub(super)
+    fn new(stream: &'stream mut S)
+      -> Self
+    {
+        Self { stream: Some(stream) }
+    }
+}
+
+impl<'stream, S : ?Sized + LendingStream + Unpin> Future for Next<'stream, S> {
+    type Output = Option<Item<'stream, S>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>)
+      -> Poll<Self::Output>
+    {
+        let stream = self.stream.take().expect("`Next` polled after completion");
+        // The lent item is only returned from the `Ready` branch: NLL problem
+        // case #3, hence `polonius()`.
+        match polonius::<dyn for<'n> WithLifetime<'n, T = Option<Item<'n, S>>>, _, _, _>(
+            stream,
+            |stream| match Pin::new(stream).poll_next(cx) {
+                | Poll::Ready(ret) => Ok(ret),
+                | Poll::Pending => Err(()),
+            },
+        )
+        {
+            | Ok(ret) => Poll::Ready(ret),
+            | Err((stream, ())) => {
+                self.stream = Some(stream);
+                Poll::Pending
+            },
+        }
+    }
+}
+
+/// The [`LendingStream`] returned by [`.map()`][LendingStream::map].
+pub
+struct Map<S, F, U : ForLt> {
+    stream: S,
+    f: F,
+    _output: PhantomData<fn() -> U>,
+}
+
+impl<S, F, U : ForLt> Map<S, F, U> {
+    pub(super)
+    fn new(stream: S, f: F)
+      -> Self
+    {
+        Self { stream, f, _output: PhantomData }
+    }
+}
+
+/// `f` is never pinned.
+impl<S : Unpin, F, U : ForLt> Unpin for Map<S, F, U> {}
+
+impl<S, F, U : ForLt> LendingStream for Map<S, F, U>
+where
+    S : LendingStream + Unpin,
+    F : for<'n> FnMut([&'n (); 0], Item<'n, S>) -> U::Of<'n>,
+{
+    type Item = U;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>)
+      -> Poll<Option<U::Of<'_>>>
+    {
+        let this = self.get_mut();
+        Pin::new(&mut this.stream)
+            .poll_next(cx)
+            .map(|item| item.map(|item| (this.f)([], item)))
+    }
+}
+
+/// The [`LendingStream`] returned by [`.filter()`][LendingStream::filter].
+pub
+struct Filter<S, F> {
+    stream: S,
+    predicate: F,
+}
+
+impl<S, F> Filter<S, F> {
+    pub(super)
+    fn new(stream: S, predicate: F)
+      -> Self
+    {
+        Self { stream, predicate }
+    }
+}
+
+/// `predicate` is never pinned.
+impl<S : Unpin, F> Unpin for Filter<S, F> {}
+
+impl<S, F> LendingStream for Filter<S, F>
+where
+    S : LendingStream + Unpin,
+    F : FnMut(&Item<'_, S>) -> bool,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>)
+      -> Poll<Option<Item<'_, S>>>
+    {
+        let this = self.get_mut();
+        let mut stream = &mut this.stream;
+        loop {
+            match polonius::<dyn for<'n> WithLifetime<'n, T = Poll<Option<Item<'n, S>>>>, _, _, _>(
+                stream,
+                |s| match Pin::new(s).poll_next(cx) {
+                    | Poll::Ready(Some(item)) if !(this.predicate)(&item) => Err(()),
+                    | ret => Ok(ret),
+                },
+            )
+            {
+                | Ok(ret) => return ret,
+                | Err((stream_, ())) => stream = stream_,
+            }
+        }
+    }
+}
+
+/// The [`Future`] returned by [`.for_each()`][LendingStream::for_each].
+pub
+struct ForEach<S, F> {
+    stream: S,
+    f: F,
+}
+
+impl<S, F> ForEach<S, F> {
+    pub(super)
+    fn new(stream: S, f: F)
+      -> Self
+    {
+        Self { stream, f }
+    }
+}
+
+/// `f` is never pinned.
+impl<S : Unpin, F> Unpin for ForEach<S, F> {}
+
+impl<S, F> Future for ForEach<S, F>
+where
+    S : LendingStream + Unpin,
+    F : FnMut(Item<'_, S>),
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>)
+      -> Poll<()>
+    {
+        let this = self.get_mut();
+        loop {
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                | Poll::Ready(Some(item)) => (this.f)(item),
+                | Poll::Ready(None) => return Poll::Ready(()),
+                | Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
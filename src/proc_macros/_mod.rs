@@ -0,0 +1,758 @@
+//! Crate not intended for direct use.
+//! Use https://docs.rs/higher-kinded-types instead.
+// Templated by `cargo-generate` using https://github.com/danielhenrymantilla/proc-macro-template
+#![allow(nonstandard_style)]
+
+use {
+    ::core::{
+        ops::Not as _,
+    },
+    ::proc_macro::{
+        Delimiter,
+        Group,
+        Literal,
+        Spacing,
+        Span,
+        TokenStream,
+        TokenTree as TT,
+    },
+};
+
+/// Proper-type-parsing backend of the shorthand syntax of `ForLt!` and `For!`
+/// (`proc-macros` feature): a drop-in replacement for the `macro_rules!`
+/// `ඞFor_munch!`, given an extra leading `[crate: $crate]`.
+#[proc_macro] #[doc(hidden)]
+pub
+fn For_munch (
+    input: TokenStream,
+) -> TokenStream
+{
+    For_munch_impl(input).unwrap_or_else(|Error(span, message)| {
+        compile_error(span, &message)
+    })
+}
+
+/// `#[derive(HasForLt)]`: see `::higher_kinded_types::HasForLt`.
+#[proc_macro_derive(HasForLt, attributes(higher_kinded_types))]
+pub
+fn HasForLt (
+    input: TokenStream,
+) -> TokenStream
+{
+    has_for::derive(input, has_for::Arity::Lt).unwrap_or_else(|Error(span, message)| {
+        compile_error(span, &message)
+    })
+}
+
+/// `#[derive(HasForLtAndLt)]`: see
+/// `::higher_kinded_types::extra_arities::HasForLtAndLt`.
+#[proc_macro_derive(HasForLtAndLt, attributes(higher_kinded_types))]
+pub
+fn HasForLtAndLt (
+    input: TokenStream,
+) -> TokenStream
+{
+    has_for::derive(input, has_for::Arity::LtAndLt).unwrap_or_else(|Error(span, message)| {
+        compile_error(span, &message)
+    })
+}
+
+/// `#[ForLt_mirror(Mirror)]`: see `::higher_kinded_types::ForLt_mirror`.
+#[proc_macro_attribute]
+pub
+fn ForLt_mirror (
+    args: TokenStream,
+    input: TokenStream,
+) -> TokenStream
+{
+    mirror::ForLt_mirror(args, input).unwrap_or_else(|Error(span, message)| {
+        compile_error(span, &message)
+    })
+}
+
+mod has_for;
+
+mod mirror;
+
+mod utils;
+
+struct Error(Span, String);
+
+type Result<T, E = Error> = ::core::result::Result<T, E>;
+
+fn bail<T> (span: Span, message: impl Into<String>)
+  -> Result<T>
+{
+    Err(Error(span, message.into()))
+}
+
+const AMBIGUOUS: &str = "\
+    ambiguous `'_` inside `Fn(…)`, `fn(…)` or `for<…>`: \
+    either elide it (e.g., `&str`), for a higher-ranked lifetime, \
+    or use the full `<'r> = …` syntax, for the lifetime of the `For` type\
+";
+
+/// `'lifetime`, as its two tokens.
+type Lifetime = [TT; 2];
+
+fn For_munch_impl (
+    input: TokenStream,
+) -> Result<TokenStream>
+{
+    let mut krate = None;
+    let mut munch = Munch {
+        same: None,
+        fresh: Vec::new().into_iter(),
+        used: vec![],
+    };
+    let mut type_input = None;
+    for tt in input {
+        let TT::Group(group) = &tt else {
+            return bail(tt.span(), "unexpected token");
+        };
+        let mut fields = group.stream().into_iter();
+        let key = match (fields.next(), fields.next()) {
+            (Some(TT::Ident(key)), Some(TT::Punct(colon)))
+                if colon.as_char() == ':'
+            => key.to_string(),
+            _ => return bail(group.span(), "expected `[key: …]`"),
+        };
+        let fields: TokenStream = fields.collect();
+        match &key[..] {
+            "crate" => krate = Some(fields),
+            "same" => munch.same = lifetimes(fields)?.pop(),
+            "fresh" => munch.fresh = lifetimes(fields)?.into_iter(),
+            "used" => munch.used = lifetimes(fields)?,
+            "input" => type_input = Some(fields),
+            // `[stack: ] [mode: default] [output: ]`
+            _ => {},
+        }
+    }
+    let (Some(krate), Some(type_input)) = (krate, type_input) else {
+        return bail(Span::call_site(), "missing `[crate: …]` or `[input: …]`");
+    };
+    let first_span =
+        type_input.clone().into_iter().next().map_or_else(Span::call_site, |tt| tt.span())
+    ;
+    let input = &mut Cursor::new(type_input);
+    let output = &mut vec![];
+    munch.ty_with_bounds(input, output, false)?;
+    if input.is_punct(0, ',') {
+        input.next();
+    }
+    if let Some(tt) = input.peek(0) {
+        return bail(tt.span(), "unexpected token: expected the end of the type");
+    }
+    let output: TokenStream = output.drain(..).collect();
+    Ok(if munch.same.is_some() {
+        if munch.used.is_empty() {
+            // `$crate::ඞ::ForLtWithoutLifetime<$output>`, whose deprecation
+            // warning is to point at the type.
+            let alias = ::proc_macro::Ident::new("ForLtWithoutLifetime", first_span);
+            let mut ret = krate;
+            ret.extend("::ඞ::".parse::<TokenStream>().unwrap());
+            ret.extend([TT::Ident(alias)]);
+            ret.extend("<".parse::<TokenStream>().unwrap());
+            ret.extend(output);
+            ret.extend(">".parse::<TokenStream>().unwrap());
+            return Ok(ret);
+        }
+        output
+    } else {
+        // `$crate::ඞFor! { @shorthand[$($used)*] $output }`
+        let mut shorthand: TokenStream = "@shorthand".parse().unwrap();
+        shorthand.extend([TT::Group(Group::new(
+            Delimiter::Bracket,
+            munch.used.into_iter().flatten().collect(),
+        ))]);
+        shorthand.extend(output);
+        let mut ret = krate;
+        ret.extend("::ඞFor!".parse::<TokenStream>().unwrap());
+        ret.extend([TT::Group(Group::new(Delimiter::Brace, shorthand))]);
+        ret
+    })
+}
+
+fn lifetimes (
+    input: TokenStream,
+) -> Result<Vec<Lifetime>>
+{
+    let input = &mut Cursor::new(input);
+    let mut ret = vec![];
+    while let Some(tt) = input.peek(0) {
+        if input.is_lifetime(0).not() {
+            return bail(tt.span(), "expected a lifetime");
+        }
+        ret.push([input.next().unwrap(), input.next().unwrap()]);
+    }
+    Ok(ret)
+}
+
+struct Munch {
+    /// `ForLt!`: every `'_` (or elided `&`) lifetime is to be this one.
+    same: Option<Lifetime>,
+    /// `For!`: every `'_` (or elided `&`) lifetime is to be the next one.
+    fresh: ::std::vec::IntoIter<Lifetime>,
+    /// The lifetime parameters (`For!`, including the named ones), or whether
+    /// `same` has been used (`ForLt!`).
+    used: Vec<Lifetime>,
+}
+
+/// The `elided` parameter tells whether we are in a `Fn(…)`, `fn(…)`, or
+/// `for<…>` context, wherein the elided lifetimes are higher-ranked ones, and
+/// are thus to be left alone.
+impl Munch {
+    /// `'_`, or the elided lifetime of a `&`, at `span`.
+    fn lifetime_param (
+        &mut self,
+        span: Span,
+        out: &'_ mut Vec<TT>,
+    ) -> Result<()>
+    {
+        let lifetime = if let Some(same) = &self.same {
+            if self.used.is_empty() {
+                self.used.push(same.clone());
+            }
+            same.clone()
+        } else {
+            let Some(fresh) = self.fresh.next() else {
+                return bail(span, "too many lifetimes in `For!` (up to 4 are supported)");
+            };
+            self.used.push(fresh.clone());
+            fresh
+        };
+        // Keep the hygiene of the lifetime, but point at the user's code.
+        out.extend(lifetime.into_iter().map(|mut tt| {
+            tt.set_span(tt.span().located_at(span));
+            tt
+        }));
+        Ok(())
+    }
+
+    fn lifetime (
+        &mut self,
+        input: &'_ mut Cursor,
+        out: &'_ mut Vec<TT>,
+        elided: bool,
+    ) -> Result<()>
+    {
+        let apostrophe = input.next().unwrap();
+        let name = input.next().unwrap();
+        if name.to_string() != "_" {
+            out.extend([apostrophe, name]);
+        } else if elided {
+            return bail(name.span(), AMBIGUOUS);
+        } else {
+            self.lifetime_param(name.span(), out)?;
+        }
+        Ok(())
+    }
+
+    /// A type, followed by `+ Bounds…`, such as the `dyn Trait + '_` in
+    /// `&(dyn Trait + '_)`.
+    fn ty_with_bounds (
+        &mut self,
+        input: &'_ mut Cursor,
+        out: &'_ mut Vec<TT>,
+        elided: bool,
+    ) -> Result<()>
+    {
+        self.ty(input, out, elided)?;
+        while input.is_punct(0, '+') {
+            out.push(input.next().unwrap());
+            self.bound(input, out, elided)?;
+        }
+        Ok(())
+    }
+
+    fn ty (
+        &mut self,
+        input: &'_ mut Cursor,
+        out: &'_ mut Vec<TT>,
+        elided: bool,
+    ) -> Result<()>
+    {
+        let Some(tt) = input.peek(0) else {
+            return bail(input.end_span(), "expected a type");
+        };
+        match tt {
+            // `(A, B)`, `(dyn Trait + '_)`, or a `$T:ty` capture.
+            | TT::Group(g) if matches!(
+                g.delimiter(),
+                Delimiter::Parenthesis | Delimiter::None,
+            ) => {
+                let g = g.clone();
+                input.next();
+                self.group(&g, out, |this, inner, out| {
+                    while inner.peek(0).is_some() {
+                        this.ty_with_bounds(inner, out, elided)?;
+                        if inner.peek(0).is_some() {
+                            inner.expect_punct(',', out)?;
+                        }
+                    }
+                    Ok(())
+                })
+            },
+            // `[T]`, `[T; N]`
+            | TT::Group(g) if g.delimiter() == Delimiter::Bracket => {
+                let g = g.clone();
+                input.next();
+                self.group(&g, out, |this, inner, out| {
+                    this.ty(inner, out, elided)?;
+                    if inner.peek(0).is_some() {
+                        // the `; N` array length: a (const) expression.
+                        inner.expect_punct(';', out)?;
+                        out.extend(inner.by_ref());
+                    }
+                    Ok(())
+                })
+            },
+            | TT::Punct(p) => match p.as_char() {
+                | '&' => {
+                    let ampersand = input.next().unwrap();
+                    let span = ampersand.span();
+                    out.push(ampersand);
+                    if input.is_lifetime(0) {
+                        self.lifetime(input, out, elided)?;
+                    } else if elided.not() {
+                        self.lifetime_param(span, out)?;
+                    }
+                    if input.is_ident(0, "mut") {
+                        out.push(input.next().unwrap());
+                    }
+                    self.ty(input, out, elided)
+                },
+                | '*' => {
+                    out.push(input.next().unwrap());
+                    if input.is_ident(0, "const").not() && input.is_ident(0, "mut").not() {
+                        return bail(input.span(), "expected `const` or `mut`");
+                    }
+                    out.push(input.next().unwrap());
+                    self.ty(input, out, elided)
+                },
+                | '!' => {
+                    out.push(input.next().unwrap());
+                    Ok(())
+                },
+                | '<' => self.qualified_path(input, out, elided),
+                | ':' => self.path_or_macro(input, out, elided),
+                | _ => bail(p.span(), "expected a type"),
+            },
+            | TT::Ident(ident) => match &ident.to_string()[..] {
+                | "_" => {
+                    out.push(input.next().unwrap());
+                    Ok(())
+                },
+                | "fn" | "unsafe" | "extern" => self.fn_pointer(input, out),
+                | "for" => {
+                    input.for_generics(out)?;
+                    if input.is_ident(0, "fn") || input.is_ident(0, "unsafe") || input.is_ident(0, "extern") {
+                        self.fn_pointer(input, out)
+                    } else {
+                        // (edition 2015) bare trait object.
+                        self.path(input, out, true)
+                    }
+                },
+                | "dyn" | "impl" => {
+                    out.push(input.next().unwrap());
+                    self.bound(input, out, elided)?;
+                    while input.is_punct(0, '+') {
+                        out.push(input.next().unwrap());
+                        self.bound(input, out, elided)?;
+                    }
+                    Ok(())
+                },
+                | _ => self.path_or_macro(input, out, elided),
+            },
+            | _ => bail(tt.span(), "expected a type"),
+        }
+    }
+
+    /// Munches the contents of `g`, and emits the so-munched group.
+    fn group (
+        &mut self,
+        g: &'_ Group,
+        out: &'_ mut Vec<TT>,
+        munch_contents: impl FnOnce(&mut Self, &mut Cursor, &mut Vec<TT>) -> Result<()>,
+    ) -> Result<()>
+    {
+        let inner = &mut Cursor::new(g.stream());
+        let inner_out = &mut vec![];
+        munch_contents(self, inner, inner_out)?;
+        if let Some(tt) = inner.peek(0) {
+            return bail(tt.span(), "unexpected token");
+        }
+        let mut munched = Group::new(g.delimiter(), inner_out.drain(..).collect());
+        munched.set_span(g.span());
+        out.push(TT::Group(munched));
+        Ok(())
+    }
+
+    /// `unsafe extern "C" fn(Args…) -> Ret`: elided lifetimes are left alone
+    /// in there.
+    fn fn_pointer (
+        &mut self,
+        input: &'_ mut Cursor,
+        out: &'_ mut Vec<TT>,
+    ) -> Result<()>
+    {
+        if input.is_ident(0, "unsafe") {
+            out.push(input.next().unwrap());
+        }
+        if input.is_ident(0, "extern") {
+            out.push(input.next().unwrap());
+            if let Some(TT::Literal(_)) = input.peek(0) {
+                out.push(input.next().unwrap());
+            }
+        }
+        if input.is_ident(0, "fn").not() {
+            return bail(input.span(), "expected `fn`");
+        }
+        out.push(input.next().unwrap());
+        let Some(TT::Group(args)) = input.peek(0).cloned() else {
+            return bail(input.span(), "expected `(`");
+        };
+        input.next();
+        self.group(&args, out, |this, inner, out| {
+            while inner.peek(0).is_some() {
+                if (0 .. 3).all(|i| inner.is_punct(i, '.')) {
+                    out.extend(inner.by_ref().take(3));
+                } else {
+                    // `name: Type`
+                    if matches!(inner.peek(0), Some(TT::Ident(_)))
+                    && inner.is_punct(1, ':')
+                    && inner.is_punct(2, ':').not()
+                    {
+                        out.extend(inner.by_ref().take(2));
+                    }
+                    this.ty(inner, out, true)?;
+                }
+                if inner.peek(0).is_some() {
+                    inner.expect_punct(',', out)?;
+                }
+            }
+            Ok(())
+        })?;
+        self.return_type(input, out)
+    }
+
+    /// `-> Ret` of `fn(…)` and `Fn(…)`: elided lifetimes are left alone in
+    /// there.
+    fn return_type (
+        &mut self,
+        input: &'_ mut Cursor,
+        out: &'_ mut Vec<TT>,
+    ) -> Result<()>
+    {
+        if input.is_punct(0, '-') && input.is_punct(1, '>') {
+            out.extend(input.by_ref().take(2));
+            self.ty(input, out, true)?;
+        }
+        Ok(())
+    }
+
+    /// `'lifetime`, `?Sized`, `(Trait)`, `for<'a> Trait<'a>`, or `Trait`.
+    fn bound (
+        &mut self,
+        input: &'_ mut Cursor,
+        out: &'_ mut Vec<TT>,
+        elided: bool,
+    ) -> Result<()>
+    {
+        if input.is_lifetime(0) {
+            return self.lifetime(input, out, elided);
+        }
+        if input.is_punct(0, '?') {
+            out.push(input.next().unwrap());
+        }
+        if let Some(TT::Group(g)) = input.peek(0) {
+            if g.delimiter() == Delimiter::Parenthesis {
+                let g = g.clone();
+                input.next();
+                return self.group(&g, out, |this, inner, out| {
+                    this.bound(inner, out, elided)
+                });
+            }
+        }
+        if input.is_ident(0, "for") {
+            input.for_generics(out)?;
+            return self.path(input, out, true);
+        }
+        self.path(input, out, elided)
+    }
+
+    /// A path type, or a type-level macro invocation.
+    fn path_or_macro (
+        &mut self,
+        input: &'_ mut Cursor,
+        out: &'_ mut Vec<TT>,
+        elided: bool,
+    ) -> Result<()>
+    {
+        self.path(input, out, elided)?;
+        if input.is_punct(0, '!') {
+            // The contents of a macro invocation are not a type: left alone.
+            out.push(input.next().unwrap());
+            match input.next() {
+                | Some(TT::Group(g)) => out.push(TT::Group(g)),
+                | _ => return bail(input.span(), "expected a macro invocation"),
+            }
+        }
+        Ok(())
+    }
+
+    /// `<Type as Trait>::Assoc`
+    fn qualified_path (
+        &mut self,
+        input: &'_ mut Cursor,
+        out: &'_ mut Vec<TT>,
+        elided: bool,
+    ) -> Result<()>
+    {
+        out.push(input.next().unwrap()); // `<`
+        self.ty(input, out, elided)?;
+        if input.is_ident(0, "as") {
+            out.push(input.next().unwrap());
+            self.path(input, out, elided)?;
+        }
+        input.expect_punct('>', out)?;
+        if (input.is_punct(0, ':') && input.is_punct(1, ':')).not() {
+            return bail(input.span(), "expected `::`");
+        }
+        self.path(input, out, elided)
+    }
+
+    /// `::a::b<'_, T>::c`, or `Fn(Args…) -> Ret`.
+    fn path (
+        &mut self,
+        input: &'_ mut Cursor,
+        out: &'_ mut Vec<TT>,
+        elided: bool,
+    ) -> Result<()>
+    {
+        if input.is_punct(0, ':') && input.is_punct(1, ':') {
+            out.extend(input.by_ref().take(2));
+        }
+        loop {
+            match input.next() {
+                | Some(TT::Ident(segment)) => out.push(TT::Ident(segment)),
+                | _ => return bail(input.prev_span(), "expected a path"),
+            }
+            if input.is_punct(0, ':') && input.is_punct(1, ':') && input.is_punct(2, '<') {
+                out.extend(input.by_ref().take(2));
+            }
+            if input.is_punct(0, '<') {
+                self.generic_args(input, out, elided)?;
+            } else if let Some(TT::Group(g)) = input.peek(0) {
+                // `Fn(Args…) -> Ret` sugar.
+                if g.delimiter() == Delimiter::Parenthesis {
+                    let g = g.clone();
+                    input.next();
+                    self.group(&g, out, |this, inner, out| {
+                        while inner.peek(0).is_some() {
+                            this.ty_with_bounds(inner, out, true)?;
+                            if inner.peek(0).is_some() {
+                                inner.expect_punct(',', out)?;
+                            }
+                        }
+                        Ok(())
+                    })?;
+                    self.return_type(input, out)?;
+                }
+            }
+            if input.is_punct(0, ':') && input.is_punct(1, ':') {
+                out.extend(input.by_ref().take(2));
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    /// `<'_, T, Assoc = U, Assoc2 : Bounds, { N + 1 }, 42>`
+    fn generic_args (
+        &mut self,
+        input: &'_ mut Cursor,
+        out: &'_ mut Vec<TT>,
+        elided: bool,
+    ) -> Result<()>
+    {
+        out.push(input.next().unwrap()); // `<`
+        loop {
+            if input.is_punct(0, '>') {
+                out.push(input.next().unwrap());
+                return Ok(());
+            }
+            match input.peek(0) {
+                | Some(_) if input.is_lifetime(0) => {
+                    self.lifetime(input, out, elided)?;
+                },
+                // `{ N + 1 }` or `42` const arguments: left alone.
+                | Some(TT::Group(g)) if g.delimiter() == Delimiter::Brace => {
+                    out.push(input.next().unwrap());
+                },
+                | Some(TT::Literal(_)) => {
+                    out.push(input.next().unwrap());
+                },
+                | Some(TT::Punct(p)) if p.as_char() == '-' => {
+                    out.extend(input.by_ref().take(2));
+                },
+                // `Assoc = Type`
+                | Some(TT::Ident(_))
+                    if input.is_punct(1, '=') && input.is_punct(2, '=').not()
+                => {
+                    out.extend(input.by_ref().take(2));
+                    self.ty(input, out, elided)?;
+                },
+                // `Assoc : Bounds`
+                | Some(TT::Ident(_))
+                    if input.is_punct(1, ':') && input.is_punct(2, ':').not()
+                => {
+                    out.extend(input.by_ref().take(2));
+                    self.bound(input, out, elided)?;
+                    while input.is_punct(0, '+') {
+                        out.push(input.next().unwrap());
+                        self.bound(input, out, elided)?;
+                    }
+                },
+                | _ => self.ty(input, out, elided)?,
+            }
+            if input.is_punct(0, '>').not() {
+                input.expect_punct(',', out)?;
+            }
+        }
+    }
+}
+
+struct Cursor {
+    tts: Vec<TT>,
+    pos: usize,
+}
+
+impl Iterator for Cursor {
+    type Item = TT;
+
+    fn next (&mut self)
+      -> Option<TT>
+    {
+        let tt = self.tts.get(self.pos).cloned()?;
+        self.pos += 1;
+        Some(tt)
+    }
+}
+
+impl Cursor {
+    fn new (tokens: TokenStream)
+      -> Cursor
+    {
+        let mut tts = vec![];
+        for tt in tokens {
+            match tt {
+                // A `$lt:lifetime` capture.
+                | TT::Group(g)
+                    if g.delimiter() == Delimiter::None
+                    && Cursor::new(g.stream()).is_lifetime(0)
+                    && g.stream().into_iter().count() == 2
+                => {
+                    tts.extend(g.stream());
+                },
+                | _ => tts.push(tt),
+            }
+        }
+        Cursor { tts, pos: 0 }
+    }
+
+    fn peek (&self, n: usize)
+      -> Option<&'_ TT>
+    {
+        self.tts.get(self.pos + n)
+    }
+
+    fn is_punct (&self, n: usize, c: char)
+      -> bool
+    {
+        matches!(self.peek(n), Some(TT::Punct(p)) if p.as_char() == c)
+    }
+
+    fn is_ident (&self, n: usize, name: &str)
+      -> bool
+    {
+        matches!(self.peek(n), Some(TT::Ident(i)) if i.to_string() == name)
+    }
+
+    fn is_lifetime (&self, n: usize)
+      -> bool
+    {
+        matches!(
+            self.peek(n),
+            Some(TT::Punct(p)) if p.as_char() == '\'' && p.spacing() == Spacing::Joint
+        )
+        && matches!(self.peek(n + 1), Some(TT::Ident(_)))
+    }
+
+    /// The span of the current token (or of the end of the input).
+    fn span (&self)
+      -> Span
+    {
+        self.peek(0).map_or_else(|| self.end_span(), TT::span)
+    }
+
+    fn prev_span (&self)
+      -> Span
+    {
+        self.tts[.. self.pos].last().map_or_else(Span::call_site, TT::span)
+    }
+
+    fn end_span (&self)
+      -> Span
+    {
+        self.tts.last().map_or_else(Span::call_site, TT::span)
+    }
+
+    fn expect_punct (&mut self, c: char, out: &'_ mut Vec<TT>)
+      -> Result<()>
+    {
+        if self.is_punct(0, c).not() {
+            return bail(self.span(), format!("expected `{c}`"));
+        }
+        out.push(self.next().unwrap());
+        Ok(())
+    }
+
+    /// `for<'a, 'b : 'a>`, left alone.
+    fn for_generics (&mut self, out: &'_ mut Vec<TT>)
+      -> Result<()>
+    {
+        out.push(self.next().unwrap()); // `for`
+        if self.is_punct(0, '<').not() {
+            return bail(self.span(), "expected `<`");
+        }
+        let mut depth = 0_usize;
+        for tt in self.by_ref() {
+            match &tt {
+                | TT::Punct(p) if p.as_char() == '<' => depth += 1,
+                | TT::Punct(p) if p.as_char() == '>' => depth -= 1,
+                | _ => {},
+            }
+            out.push(tt);
+            if depth == 0 {
+                return Ok(());
+            }
+        }
+        bail(self.end_span(), "expected `>`")
+    }
+}
+
+fn compile_error (
+    span: Span,
+    message: &'_ str,
+) -> TokenStream
+{
+    let mut ret: TokenStream = "::core::compile_error!".parse().unwrap();
+    ret.extend([TT::Group(Group::new(
+        Delimiter::Brace,
+        [TT::Literal(Literal::string(message))].into_iter().collect(),
+    ))]);
+    ret.into_iter()
+        .map(|mut tt| { tt.set_span(span); tt })
+        .collect()
+}
@@ -0,0 +1,165 @@
+//! `#[derive(HasForLt)]` and `#[derive(HasForLtAndLt)]`.
+
+use {
+    super::*,
+    crate::utils::*,
+};
+
+/// Which trait to derive.
+#[derive(Clone, Copy)]
+pub(crate)
+enum Arity {
+    /// `HasForLt`, for types with exactly one lifetime parameter.
+    Lt,
+    /// `HasForLtAndLt`, for types with exactly two lifetime parameters.
+    LtAndLt,
+}
+
+pub(crate)
+fn derive (
+    input: TokenStream,
+    arity: Arity,
+) -> Result<TokenStream>
+{
+    let input = &mut Cursor::new(input);
+    let mut krate = parse("::higher_kinded_types");
+    // `#[attrs] pub(…) struct`
+    let name = loop {
+        match input.next() {
+            | Some(TT::Group(attr)) if attr.delimiter() == Delimiter::Bracket => {
+                if let Some(path) = crate_override(attr)? {
+                    krate = path;
+                }
+            },
+            | Some(TT::Ident(kw))
+                if matches!(&kw.to_string()[..], "struct" | "enum" | "union")
+            => match input.next() {
+                | Some(name @ TT::Ident(_)) => break name,
+                | _ => return bail(input.prev_span(), "expected a type name"),
+            },
+            | Some(_) => {},
+            | None => return bail(Span::call_site(), "expected a `struct`, `enum` or `union`"),
+        }
+    };
+    let params = if input.is_punct(0, '<') {
+        input.next();
+        generic_params(input)?
+    } else {
+        vec![]
+    };
+    // `where …` clause: everything up until the last token, be it the `{ … }`
+    // body or the `;` terminator (of tuple or unit structs).
+    if matches!(input.peek(0), Some(TT::Group(g)) if g.delimiter() == Delimiter::Parenthesis) {
+        input.next();
+    }
+    let mut where_clause: Vec<TT> = input.collect();
+    where_clause.pop();
+
+    let lifetimes = params.iter().filter(|p| matches!(p.kind, ParamKind::Lifetime(_))).count();
+    let (module, trait_name, assoc, macro_name, fresh): (_, _, _, _, &[&str]) = match arity {
+        | Arity::Lt => ("", "HasForLt", "ForLt", "ForLt", &["'ඞ"]),
+        | Arity::LtAndLt => (
+            "::extra_arities", "HasForLtAndLt", "ForLtAndLt", "For", &["'ඞ0", "'ඞ1"],
+        ),
+    };
+    if lifetimes != fresh.len() {
+        return bail(name.span(), match (arity, lifetimes) {
+            | (Arity::Lt, 2) => format!(
+                "`{name}` has two lifetime parameters: use `#[derive(HasForLtAndLt)]` instead",
+            ),
+            | (_, 0) => format!(
+                "`{name}` has no lifetime parameter: for a type which does not depend on \
+                any lifetime, use `ForFixed<{name}>` instead",
+            ),
+            | (Arity::Lt, _) => format!(
+                "`#[derive(HasForLt)]` requires exactly one lifetime parameter, \
+                but `{name}` has {lifetimes}",
+            ),
+            | (Arity::LtAndLt, _) => format!(
+                "`#[derive(HasForLtAndLt)]` requires exactly two lifetime parameters, \
+                but `{name}` has {lifetimes}",
+            ),
+        });
+    }
+
+    // `<'a, T, N>` for the `Self` type, and `<'ඞ, T, N>` for the `For` one.
+    let mut self_args = vec![];
+    let mut for_args = vec![];
+    let mut fresh_lifetimes = fresh.iter();
+    for param in &params {
+        match &param.kind {
+            | ParamKind::Lifetime(lifetime) => {
+                self_args.extend(lifetime.iter().cloned());
+                for_args.extend(parse(fresh_lifetimes.next().unwrap()));
+            },
+            | ParamKind::Named(name) => {
+                self_args.push(name.clone());
+                for_args.push(name.clone());
+            },
+        }
+        self_args.extend(parse(","));
+        for_args.extend(parse(","));
+    }
+    // impl<$($decl),*> $krate$module::$Trait for $Name<$self_args>
+    // $where_clause
+    // {
+    //     type $Assoc = $krate$module::$macro!(
+    //         <$fresh> = $Name<$for_args>
+    //     );
+    // }
+    let mut ret = parse("impl");
+    ret.extend(parse("<"));
+    for param in params {
+        ret.extend(param.decl);
+        ret.extend(parse(","));
+    }
+    ret.extend(parse(">"));
+    ret.extend(krate.iter().cloned());
+    ret.extend(parse(&format!("{module}::{trait_name} for")));
+    ret.push(name.clone());
+    ret.extend(angle_brackets(self_args));
+    ret.extend(where_clause);
+    let mut body = parse(&format!("type {assoc} ="));
+    body.extend(krate);
+    body.extend(parse(&format!("{module}::{macro_name}!")));
+    let mut macro_input = parse("<");
+    macro_input.extend(parse(&fresh.join(", ")));
+    macro_input.extend(parse("> ="));
+    macro_input.push(name);
+    macro_input.extend(angle_brackets(for_args));
+    body.push(TT::Group(Group::new(Delimiter::Parenthesis, macro_input.into_iter().collect())));
+    body.extend(parse(";"));
+    ret.push(TT::Group(Group::new(Delimiter::Brace, body.into_iter().collect())));
+    Ok(ret.into_iter().collect())
+}
+
+/// The `path` of a `#[higher_kinded_types(crate = path)]` attribute, if that is
+/// what `attr` is.
+fn crate_override (attr: Group)
+  -> Result<Option<Vec<TT>>>
+{
+    let mut tts = attr.stream().into_iter();
+    match tts.next() {
+        | Some(name) if is_ident(&name, "higher_kinded_types") => {},
+        | _ => return Ok(None),
+    }
+    let error = || bail(attr.span(), "\
+        expected `#[higher_kinded_types(crate = path::to::higher_kinded_types)]`\
+    ");
+    let args = match (tts.next(), tts.next()) {
+        | (Some(TT::Group(args)), None) if args.delimiter() == Delimiter::Parenthesis => args,
+        | _ => return error(),
+    };
+    let mut args = args.stream().into_iter();
+    match (args.next(), args.next()) {
+        | (Some(krate), Some(TT::Punct(eq)))
+            if is_ident(&krate, "crate") && eq.as_char() == '='
+        => {},
+        | _ => return error(),
+    }
+    let path: Vec<TT> = args.collect();
+    if path.is_empty() {
+        return error();
+    }
+    Ok(Some(path))
+}
@@ -0,0 +1,1164 @@
+//! `#[ForLt_mirror(Mirror)]`.
+
+use {
+    super::*,
+    crate::utils::*,
+};
+
+/// The type parameter of the blanket impls.
+const SELF: &str = "ඞSelf";
+
+struct Trait {
+    /// `pub(…)`.
+    vis: Vec<TT>,
+    /// `unsafe`, if any.
+    unsafety: Vec<TT>,
+    name: TT,
+    params: Vec<Param>,
+    /// Without the leading `:`.
+    supertraits: Vec<TT>,
+    /// Without the leading `where`.
+    where_clause: Vec<TT>,
+    items: Vec<Item>,
+}
+
+struct Item {
+    attrs: Vec<TT>,
+    kind: ItemKind,
+}
+
+enum ItemKind {
+    /// `type Name<'lt>` (`where Self : 'lt`, when `outlives_self`).
+    Gat { name: TT, lifetime: Lifetime, outlives_self: bool },
+    /// `type Name : Bounds`.
+    Type { name: TT, bounds: Vec<TT> },
+    /// `const NAME : Type = Default`.
+    Const { name: TT, ty: Vec<TT>, default: Vec<TT> },
+    Fn(Method),
+}
+
+struct Method {
+    /// `unsafe`, `extern "C"`.
+    qualifiers: Vec<TT>,
+    name: TT,
+    params: Vec<Param>,
+    /// The `self` parameter, verbatim, and how it is to be passed along.
+    receiver: Option<(Vec<TT>, Receiver)>,
+    /// The `pattern : Type` parameters.
+    args: Vec<(Vec<TT>, Vec<TT>)>,
+    /// `-> Ret`, if any.
+    ret: Vec<TT>,
+    /// `where …`, if any.
+    where_clause: Vec<TT>,
+    /// The default `{ … }` body, if any.
+    body: Option<TT>,
+}
+
+enum Receiver {
+    /// `self`.
+    Value,
+    /// `&self`, or `&'lt self`.
+    Ref,
+    /// `&mut self`, or `&'lt mut self`.
+    RefMut,
+}
+
+#[derive(Clone, Copy)]
+enum Style<'mirror> {
+    /// `Self::Assoc<'lt>` becomes `<<Self as Mirror>::Assoc as ForLt>::Of<'lt>`.
+    Mirror,
+    /// `Self::Assoc<'lt>` becomes `<Self as Trait>::Assoc<'lt>`.
+    Gat,
+    /// Like [`Style::Gat`], but for the `where Self : 'lt` GATs, which become
+    /// `<Self as MirrorඞAssoc<'lt>>::T`, through their [`helper()`] trait.
+    Helper(&'mirror TT),
+}
+
+pub(crate)
+fn ForLt_mirror (
+    args: TokenStream,
+    input: TokenStream,
+) -> Result<TokenStream>
+{
+    let mirror = match &Cursor::new(args).collect::<Vec<_>>()[..] {
+        | [name @ TT::Ident(_)] => name.clone(),
+        | _ => return bail(Span::call_site(), "expected `#[ForLt_mirror(NameOfTheMirrorTrait)]`"),
+    };
+    let tr = parse_trait(input.clone())?;
+    let mut ret: Vec<TT> = input.into_iter().collect();
+    for item in &tr.items {
+        if let ItemKind::Gat { name, lifetime, outlives_self: true } = &item.kind {
+            ret.extend(helper(&tr, &mirror, item, name, lifetime));
+        }
+    }
+    ret.extend(mirror_trait(&tr, &mirror));
+    ret.extend(forwarding_trait(&tr, &mirror));
+    ret.extend(forward_impl(&tr, &mirror));
+    ret.extend(reverse_impl(&tr, &mirror)?);
+    Ok(ret.into_iter().collect())
+}
+
+fn parse_trait (
+    input: TokenStream,
+) -> Result<Trait>
+{
+    let input = &mut Cursor::new(input);
+    let mut vis = vec![];
+    let mut unsafety = vec![];
+    loop {
+        match input.next() {
+            | Some(TT::Punct(p)) if p.as_char() == '#' => {
+                input.next(); // `[…]`
+            },
+            | Some(TT::Ident(i)) if i.to_string() == "trait" => break,
+            | Some(TT::Ident(i)) if i.to_string() == "unsafe" => unsafety.push(TT::Ident(i)),
+            | Some(TT::Ident(i)) if i.to_string() == "auto" => {
+                return bail(i.span(), "`#[ForLt_mirror]` does not support `auto` traits");
+            },
+            | Some(tt) => vis.push(tt),
+            | None => return bail(Span::call_site(), "expected a trait definition"),
+        }
+    }
+    let name = match input.next() {
+        | Some(name @ TT::Ident(_)) => name,
+        | _ => return bail(input.prev_span(), "expected the name of the trait"),
+    };
+    let params = if input.is_punct(0, '<') {
+        input.next();
+        generic_params(input)?
+    } else {
+        vec![]
+    };
+    let mut rest: Vec<TT> = input.collect();
+    let body = match rest.pop() {
+        | Some(TT::Group(g)) if g.delimiter() == Delimiter::Brace => g,
+        | _ => return bail(name.span(), "expected the body of the trait"),
+    };
+    // `: Supertraits where Clauses`
+    let mut supertraits = vec![];
+    let mut where_clause = vec![];
+    let mut rest = rest.into_iter();
+    for tt in rest.by_ref() {
+        if matches!(&tt, TT::Ident(i) if i.to_string() == "where") {
+            break;
+        }
+        supertraits.push(tt);
+    }
+    where_clause.extend(rest);
+    if matches!(supertraits.first(), Some(TT::Punct(p)) if p.as_char() == ':') {
+        supertraits.remove(0);
+    }
+
+    let body = &mut Cursor::new(body.stream());
+    let mut items = vec![];
+    while body.peek(0).is_some() {
+        let mut attrs = vec![];
+        while body.is_punct(0, '#') {
+            attrs.extend(body.next());
+            attrs.extend(body.next()); // `[…]`
+        }
+        let kind = if body.is_ident(0, "type") {
+            assoc_type(body)?
+        } else if body.is_ident(0, "const") {
+            assoc_const(body)?
+        } else {
+            ItemKind::Fn(method(body)?)
+        };
+        items.push(Item { attrs, kind });
+    }
+    Ok(Trait { vis, unsafety, name, params, supertraits, where_clause, items })
+}
+
+fn assoc_type (
+    input: &mut Cursor,
+) -> Result<ItemKind>
+{
+    input.next(); // `type`
+    let name = match input.next() {
+        | Some(name @ TT::Ident(_)) => name,
+        | _ => return bail(input.prev_span(), "expected the name of the associated type"),
+    };
+    let params = if input.is_punct(0, '<') {
+        input.next();
+        generic_params(input)?
+    } else {
+        vec![]
+    };
+    let rest = until_semicolon(input)?;
+    // `: Bounds where Clauses = Default`
+    let end = top_level_position(&rest, '=').unwrap_or(rest.len());
+    let (bounds, where_clause) = match rest[.. end].iter().position(|tt| is_ident(tt, "where")) {
+        | Some(i) => (&rest[.. i], &rest[i ..]),
+        | None => (&rest[.. end], &[][..]),
+    };
+    match &params[..] {
+        | [] => {
+            if let Some(tt) = where_clause.first() {
+                return bail(tt.span(), "`#[ForLt_mirror]` does not support this `where` clause");
+            }
+            Ok(ItemKind::Type { name, bounds: bounds.to_vec() })
+        },
+        | [Param { kind: ParamKind::Lifetime(lifetime), .. }] => {
+            if let Some(tt) = bounds.first() {
+                return bail(tt.span(), "\
+                    `#[ForLt_mirror]` does not support bounds on the GATs, \
+                    since `ForLt` types cannot express them\
+                ");
+            }
+            let outlives_self = match where_clause {
+                | [] => false,
+                | [_where, this, colon, apostrophe, lt, trailing @ ..]
+                    if is_ident(this, "Self")
+                    && colon.to_string() == ":"
+                    && apostrophe.to_string() == "'"
+                    && lt.to_string() == lifetime[1].to_string()
+                    && matches!(trailing, [] | [TT::Punct(_)])
+                => true,
+                | _ => return bail(where_clause[0].span(), format!(
+                    "`#[ForLt_mirror]` only supports `where Self : '{}` clauses on the GATs",
+                    lifetime[1],
+                )),
+            };
+            Ok(ItemKind::Gat { name, lifetime: lifetime.clone(), outlives_self })
+        },
+        | _ => bail(name.span(), "\
+            `#[ForLt_mirror]` only supports GATs with exactly one lifetime parameter\
+        "),
+    }
+}
+
+fn assoc_const (
+    input: &mut Cursor,
+) -> Result<ItemKind>
+{
+    input.next(); // `const`
+    let name = match input.next() {
+        | Some(name @ TT::Ident(_)) => name,
+        | _ => return bail(input.prev_span(), "expected the name of the associated constant"),
+    };
+    if input.is_punct(0, ':').not() {
+        return bail(input.span(), "expected `:`");
+    }
+    input.next();
+    let mut ty = until_semicolon(input)?;
+    let default = match top_level_position(&ty, '=') {
+        | Some(i) => ty.split_off(i),
+        | None => vec![],
+    };
+    Ok(ItemKind::Const { name, ty, default })
+}
+
+fn method (
+    input: &mut Cursor,
+) -> Result<Method>
+{
+    let mut qualifiers = vec![];
+    while input.is_ident(0, "fn").not() {
+        match input.next() {
+            | Some(TT::Ident(i)) if matches!(&i.to_string()[..], "unsafe" | "extern") => {
+                qualifiers.push(TT::Ident(i));
+            },
+            | Some(TT::Literal(abi)) if qualifiers.last().map_or(false, |q| is_ident(q, "extern")) => {
+                qualifiers.push(TT::Literal(abi));
+            },
+            | Some(tt) => return bail(tt.span(), "unsupported item in a `#[ForLt_mirror]` trait"),
+            | None => return bail(input.end_span(), "expected `fn`"),
+        }
+    }
+    input.next(); // `fn`
+    let name = match input.next() {
+        | Some(name @ TT::Ident(_)) => name,
+        | _ => return bail(input.prev_span(), "expected the name of the method"),
+    };
+    let params = if input.is_punct(0, '<') {
+        input.next();
+        generic_params(input)?
+    } else {
+        vec![]
+    };
+    let args = match input.next() {
+        | Some(TT::Group(g)) if g.delimiter() == Delimiter::Parenthesis => g,
+        | _ => return bail(input.prev_span(), "expected the parameters of the method"),
+    };
+    let mut receiver = None;
+    let mut typed_args = vec![];
+    for mut arg in split_commas(args.stream()) {
+        while matches!(arg.first(), Some(TT::Punct(p)) if p.as_char() == '#') {
+            arg.drain(.. 2);
+        }
+        let colon = top_level_position(&arg, ':');
+        if arg[.. colon.unwrap_or(arg.len())].iter().any(|tt| is_ident(tt, "self")) {
+            if let Some(colon) = colon {
+                return bail(arg[colon].span(), "\
+                    `#[ForLt_mirror]` does not support typed `self` receivers\
+                ");
+            }
+            let kind = match &arg[..] {
+                | [TT::Punct(p), ..] if p.as_char() == '&' => {
+                    if arg.iter().any(|tt| is_ident(tt, "mut")) {
+                        Receiver::RefMut
+                    } else {
+                        Receiver::Ref
+                    }
+                },
+                | _ => Receiver::Value,
+            };
+            receiver = Some((arg, kind));
+            continue;
+        }
+        let Some(colon) = colon else {
+            return bail(arg.first().map_or_else(|| args.span(), TT::span), "expected `pattern: Type`");
+        };
+        let ty = arg.split_off(colon + 1);
+        arg.pop(); // `:`
+        typed_args.push((arg, ty));
+    }
+    // `-> Ret where Clauses`, up until the `;` or the `{ … }` body.
+    let mut ret = vec![];
+    let mut where_clause = vec![];
+    let mut depth = 0_usize;
+    let body = loop {
+        let Some(tt) = input.peek(0) else {
+            return bail(input.end_span(), "expected `;` or the body of the method");
+        };
+        match tt {
+            | TT::Punct(p) if p.as_char() == '>' && prev_is_dash(input) => {},
+            | TT::Punct(p) if p.as_char() == '<' => depth += 1,
+            | TT::Punct(p) if p.as_char() == '>' => depth = depth.saturating_sub(1),
+            | TT::Punct(p) if depth == 0 && p.as_char() == ';' => {
+                input.next();
+                break None;
+            },
+            | TT::Group(g) if depth == 0 && g.delimiter() == Delimiter::Brace => {
+                break input.next();
+            },
+            | _ => {},
+        }
+        let tt = input.next().unwrap();
+        if where_clause.is_empty().not() || is_ident(&tt, "where") {
+            where_clause.push(tt);
+        } else {
+            ret.push(tt);
+        }
+    };
+    Ok(Method {
+        qualifiers,
+        name,
+        params,
+        receiver,
+        args: typed_args,
+        ret,
+        where_clause,
+        body,
+    })
+}
+
+/// The tokens up until the next `;`, which is consumed.
+fn until_semicolon (
+    input: &mut Cursor,
+) -> Result<Vec<TT>>
+{
+    let mut ret = vec![];
+    loop {
+        match input.next() {
+            | Some(TT::Punct(p)) if p.as_char() == ';' => return Ok(ret),
+            | Some(tt) => ret.push(tt),
+            | None => return bail(input.end_span(), "expected `;`"),
+        }
+    }
+}
+
+impl Trait {
+    /// `<'a, T, N>`, or nothing.
+    fn args (&self)
+      -> Vec<TT>
+    {
+        if self.params.is_empty() {
+            return vec![];
+        }
+        angle_brackets(self.inner_args())
+    }
+
+    /// `'a, T, N,`
+    fn inner_args (&self)
+      -> Vec<TT>
+    {
+        let mut ret = vec![];
+        for param in &self.params {
+            match &param.kind {
+                | ParamKind::Lifetime(lifetime) => ret.extend(lifetime.iter().cloned()),
+                | ParamKind::Named(name) => ret.push(name.clone()),
+            }
+            ret.extend(parse(","));
+        }
+        ret
+    }
+
+    /// `'a : 'b, T : Bounds, const N : usize,` (with the defaults, if `defaults`).
+    fn decls (&self, defaults: bool)
+      -> Vec<TT>
+    {
+        let mut ret = vec![];
+        for param in &self.params {
+            ret.extend(param.decl.iter().cloned());
+            if defaults {
+                ret.extend(param.default.iter().cloned());
+            }
+            ret.extend(parse(","));
+        }
+        ret
+    }
+
+    /// `where Clauses,` (with the `where`).
+    fn where_clause (&self)
+      -> Vec<TT>
+    {
+        let mut ret = parse("where");
+        ret.extend(self.where_clause.iter().cloned());
+        if matches!(self.where_clause.last(), None | Some(TT::Punct(_))).not() {
+            ret.extend(parse(","));
+        }
+        ret
+    }
+
+    /// `<ඞSelf as Trait<'a, T, N>>`
+    fn qualified_self (&self, trait_name: &TT)
+      -> Vec<TT>
+    {
+        let mut ret = parse(SELF);
+        ret.extend(parse("as"));
+        ret.push(trait_name.clone());
+        ret.extend(self.args());
+        angle_brackets(ret)
+    }
+
+    /// Whether `tokens` mention some `Self::Gat<'_>` which is `where Self : '_`.
+    fn mentions_outliving_gat (&self, tokens: &[TT])
+      -> bool
+    {
+        tokens.iter().enumerate().any(|(i, tt)| match tt {
+            | TT::Group(g) => {
+                self.mentions_outliving_gat(&g.stream().into_iter().collect::<Vec<_>>())
+            },
+            | _ => is_ident(tt, "Self") && matches!(
+                tokens.get(i + 3),
+                Some(name) if self.items.iter().any(|item| matches!(
+                    &item.kind,
+                    ItemKind::Gat { name: gat, outlives_self: true, .. }
+                    if gat.to_string() == name.to_string()
+                )),
+            ),
+        })
+    }
+
+    /// Whether `method` is to be forwarded through the [`forwarding_trait()`].
+    fn forwards_through_helpers (&self, method: &Method)
+      -> bool
+    {
+        self.mentions_outliving_gat(&method.ret)
+        || method.args.iter().any(|(_, ty)| self.mentions_outliving_gat(ty))
+    }
+
+    /// Qualifies the `Self::Assoc` types, so that they remain unambiguous in
+    /// the blanket impls (whose `ඞSelf` implements both traits), and maps the
+    /// `Self::Gat<'lt>` to `<Self::Gat as ForLt>::Of<'lt>`, for the `Mirror`.
+    fn rewrite (&self, tokens: impl IntoIterator<Item = TT>, trait_name: &TT, style: Style)
+      -> Vec<TT>
+    {
+        let tts: Vec<TT> = tokens.into_iter().collect();
+        let mut ret = vec![];
+        let mut i = 0;
+        while i < tts.len() {
+            if let TT::Group(g) = &tts[i] {
+                let mut group = Group::new(
+                    g.delimiter(),
+                    self.rewrite(g.stream(), trait_name, style).into_iter().collect(),
+                );
+                group.set_span(g.span());
+                ret.push(TT::Group(group));
+                i += 1;
+                continue;
+            }
+            let assoc = match tts.get(i .. i + 4) {
+                | Some([this, colon, _, name])
+                    if is_ident(this, "Self")
+                    && matches!(colon, TT::Punct(p) if p.as_char() == ':' && p.spacing() == Spacing::Joint)
+                => self.items.iter().find(|item| match &item.kind {
+                    | ItemKind::Gat { name: assoc, .. } | ItemKind::Type { name: assoc, .. } => {
+                        assoc.to_string() == name.to_string()
+                    },
+                    | _ => false,
+                }),
+                | _ => None,
+            };
+            // `<Self as Trait<'a, T, N>>::Assoc`
+            let mut qualified = parse("Self as");
+            qualified.push(trait_name.clone());
+            qualified.extend(self.args());
+            let mut qualified = angle_brackets(qualified);
+            qualified.extend(parse("::"));
+            match assoc.map(|item| &item.kind) {
+                | Some(ItemKind::Type { name, .. }) => {
+                    ret.extend(qualified);
+                    ret.push(name.clone());
+                    i += 4;
+                },
+                | Some(ItemKind::Gat { name, outlives_self, .. })
+                    if matches!(tts.get(i + 4), Some(TT::Punct(p)) if p.as_char() == '<')
+                    && matches!(tts.get(i + 7), Some(TT::Punct(p)) if p.as_char() == '>')
+                => {
+                    let lifetime = &tts[i + 5 ..= i + 6];
+                    qualified.push(name.clone());
+                    match style {
+                        | Style::Mirror => {
+                            // `<<Self as Mirror>::Gat as ForLt>::Of<'lt>`
+                            qualified.extend(parse("as ::higher_kinded_types::ForLt"));
+                            ret.extend(angle_brackets(qualified));
+                            ret.extend(parse("::Of"));
+                            ret.extend(angle_brackets(lifetime.to_vec()));
+                        },
+                        | Style::Helper(mirror) if *outlives_self => {
+                            // `<Self as MirrorඞGat<'lt, 'a, T, N>>::T`
+                            let mut helper = parse("Self as");
+                            helper.push(helper_name(mirror, name));
+                            let mut args = lifetime.to_vec();
+                            args.extend(parse(","));
+                            args.extend(self.inner_args());
+                            helper.extend(angle_brackets(args));
+                            ret.extend(angle_brackets(helper));
+                            ret.extend(parse("::T"));
+                        },
+                        | Style::Gat | Style::Helper(_) => {
+                            ret.extend(qualified);
+                            ret.extend(angle_brackets(lifetime.to_vec()));
+                        },
+                    }
+                    i += 8;
+                },
+                | _ => {
+                    ret.push(tts[i].clone());
+                    i += 1;
+                },
+            }
+        }
+        ret
+    }
+}
+
+/// For a `where Self : 'lt` GAT, `ForLt!(<'lt> = <ඞSelf as Trait>::Gat<'lt>)`
+/// would require `ඞSelf : 'static` (for `ForLt` to be implemented), so the
+/// `ForLt` type is defined through a hidden helper trait instead, whose
+/// `ImplicitBound = &'lt Self` lets it be implemented for every `'lt`.
+fn helper (
+    tr: &Trait,
+    mirror: &TT,
+    item: &Item,
+    name: &TT,
+    lifetime: &Lifetime,
+) -> Vec<TT>
+{
+    let helper = helper_name(mirror, name);
+    let cfgs = cfg_attrs(&item.attrs);
+    // #[doc(hidden)]
+    // $vis trait $Helper<'lt, $decls ඞImplicitBound = &'lt Self> {
+    //     type T;
+    // }
+    let mut ret = cfgs.clone();
+    ret.extend(parse("#[doc(hidden)]"));
+    ret.extend(tr.vis.iter().cloned());
+    ret.extend(parse("trait"));
+    ret.push(helper.clone());
+    let mut params = lifetime.to_vec();
+    params.extend(parse(","));
+    params.extend(tr.decls(false));
+    params.extend(parse("ඞImplicitBound = &"));
+    params.extend(lifetime.iter().cloned());
+    params.extend(parse("Self"));
+    ret.extend(angle_brackets(params));
+    ret.push(braces(parse("type T;")));
+    // impl<'lt, $decls ඞSelf : $Trait<…>> $Helper<'lt, …> for ඞSelf
+    // where
+    //     $where_clause
+    // {
+    //     type T = <ඞSelf as $Trait<…>>::$Gat<'lt>;
+    // }
+    ret.extend(cfgs);
+    ret.extend(parse("impl"));
+    let mut params = lifetime.to_vec();
+    params.extend(parse(","));
+    params.extend(tr.decls(false));
+    params.extend(parse(SELF));
+    params.extend(parse(":"));
+    params.push(tr.name.clone());
+    params.extend(tr.args());
+    ret.extend(angle_brackets(params));
+    ret.push(helper);
+    let mut args = lifetime.to_vec();
+    args.extend(parse(","));
+    args.extend(tr.inner_args());
+    ret.extend(angle_brackets(args));
+    ret.extend(parse("for"));
+    ret.extend(parse(SELF));
+    ret.extend(tr.where_clause());
+    let mut body = parse("type T =");
+    body.extend(tr.qualified_self(&tr.name));
+    body.extend(parse("::"));
+    body.push(name.clone());
+    body.extend(angle_brackets(lifetime.to_vec()));
+    body.extend(parse(";"));
+    ret.push(braces(body));
+    ret
+}
+
+fn helper_name (mirror: &TT, gat: &TT)
+  -> TT
+{
+    parse(&format!("{mirror}ඞ{gat}")).pop().unwrap()
+}
+
+fn mirror_trait (
+    tr: &Trait,
+    mirror: &TT,
+) -> Vec<TT>
+{
+    let mut ret = vec![];
+    ret.extend(parse(&format!(
+        "#[doc = \" [`ForLt`](::higher_kinded_types::ForLt)-based mirror of [`{}`], \
+        implemented by every `impl {0}`.\"]",
+        tr.name,
+    )));
+    ret.extend(tr.vis.iter().cloned());
+    ret.extend(tr.unsafety.iter().cloned());
+    ret.extend(parse("trait"));
+    ret.push(mirror.clone());
+    if tr.params.is_empty().not() {
+        ret.extend(angle_brackets(tr.decls(true)));
+    }
+    if tr.supertraits.is_empty().not() {
+        ret.extend(parse(":"));
+        ret.extend(tr.rewrite(tr.supertraits.iter().cloned(), mirror, Style::Mirror));
+    }
+    if tr.where_clause.is_empty().not() {
+        ret.extend(tr.rewrite(tr.where_clause(), mirror, Style::Mirror));
+    }
+    let mut body = vec![];
+    for item in &tr.items {
+        body.extend(item.attrs.iter().cloned());
+        match &item.kind {
+            | ItemKind::Gat { name, .. } => {
+                body.extend(parse("type"));
+                body.push(name.clone());
+                body.extend(parse(": ::higher_kinded_types::ForLt;"));
+            },
+            | ItemKind::Type { name, bounds } => {
+                body.extend(parse("type"));
+                body.push(name.clone());
+                body.extend(tr.rewrite(bounds.iter().cloned(), mirror, Style::Mirror));
+                body.extend(parse(";"));
+            },
+            | ItemKind::Const { name, ty, default } => {
+                body.extend(parse("const"));
+                body.push(name.clone());
+                body.extend(parse(":"));
+                body.extend(tr.rewrite(ty.iter().cloned(), mirror, Style::Mirror));
+                body.extend(tr.rewrite(default.iter().cloned(), mirror, Style::Mirror));
+                body.extend(parse(";"));
+            },
+            | ItemKind::Fn(method) => {
+                let args = method.args.iter().map(|(pat, ty)| {
+                    let mut arg = pat.clone();
+                    arg.extend(parse(":"));
+                    arg.extend(tr.rewrite(ty.iter().cloned(), mirror, Style::Mirror));
+                    arg
+                });
+                body.extend(signature(tr, method, mirror, Style::Mirror, false, args));
+                match &method.body {
+                    | Some(block) => body.extend(tr.rewrite([block.clone()], mirror, Style::Mirror)),
+                    | None => body.extend(parse(";")),
+                }
+            },
+        }
+    }
+    ret.push(braces(body));
+    ret
+}
+
+/// `impl<…, ඞSelf : Trait<…>> Mirror<…> for ඞSelf`
+fn forward_impl (
+    tr: &Trait,
+    mirror: &TT,
+) -> Vec<TT>
+{
+    let mut ret = tr.unsafety.clone();
+    ret.extend(parse("impl"));
+    let mut params = tr.decls(false);
+    params.extend(parse(SELF));
+    params.extend(parse(":"));
+    params.push(tr.name.clone());
+    params.extend(tr.args());
+    ret.extend(angle_brackets(params));
+    ret.push(mirror.clone());
+    ret.extend(tr.args());
+    ret.extend(parse("for"));
+    ret.extend(parse(SELF));
+    ret.extend(tr.where_clause());
+    let qualified_self = tr.qualified_self(&tr.name);
+    let mut body = vec![];
+    for item in &tr.items {
+        body.extend(cfg_attrs(&item.attrs));
+        match &item.kind {
+            | ItemKind::Gat { name, outlives_self, .. } => {
+                // `ForLt!(<'ඞ> = <ඞSelf as Trait>::Gat<'ඞ>)`, or, through the
+                // helper trait, `ForLt!(<'ඞ> = <ඞSelf as Helper<'ඞ>>::T)`.
+                let mut for_lt = parse("<'ඞ> =");
+                if *outlives_self {
+                    let mut helper = parse(SELF);
+                    helper.extend(parse("as"));
+                    helper.push(helper_name(mirror, name));
+                    let mut args = parse("'ඞ,");
+                    args.extend(tr.inner_args());
+                    helper.extend(angle_brackets(args));
+                    for_lt.extend(angle_brackets(helper));
+                    for_lt.extend(parse("::T"));
+                    // where for<'ඞ> ඞSelf : Helper<'ඞ, …>,
+                    ret.extend(parse(&format!("for<'ඞ> {SELF} :")));
+                    ret.push(helper_name(mirror, name));
+                    let mut args = parse("'ඞ,");
+                    args.extend(tr.inner_args());
+                    ret.extend(angle_brackets(args));
+                    ret.extend(parse(","));
+                } else {
+                    for_lt.extend(qualified_self.iter().cloned());
+                    for_lt.extend(parse("::"));
+                    for_lt.push(name.clone());
+                    for_lt.extend(parse("<'ඞ>"));
+                }
+                body.extend(parse("type"));
+                body.push(name.clone());
+                body.extend(parse("= ::higher_kinded_types::ForLt!"));
+                body.push(TT::Group(Group::new(Delimiter::Parenthesis, for_lt.into_iter().collect())));
+                body.extend(parse(";"));
+            },
+            | ItemKind::Type { name, .. } => {
+                body.extend(parse("type"));
+                body.push(name.clone());
+                body.extend(parse("="));
+                body.extend(qualified_self.iter().cloned());
+                body.extend(parse("::"));
+                body.push(name.clone());
+                body.extend(parse(";"));
+            },
+            | ItemKind::Const { name, ty, .. } => {
+                body.extend(parse("const"));
+                body.push(name.clone());
+                body.extend(parse(":"));
+                body.extend(tr.rewrite(ty.iter().cloned(), mirror, Style::Mirror));
+                body.extend(parse("="));
+                body.extend(qualified_self.iter().cloned());
+                body.extend(parse("::"));
+                body.push(name.clone());
+                body.extend(parse(";"));
+            },
+            | ItemKind::Fn(method) => {
+                let args = method.args.iter().enumerate().map(|(i, (_, ty))| {
+                    let mut arg = vec![arg_name(i)];
+                    arg.extend(parse(":"));
+                    arg.extend(tr.rewrite(ty.iter().cloned(), mirror, Style::Mirror));
+                    arg
+                });
+                body.extend(signature(tr, method, mirror, Style::Mirror, true, args));
+                // The `Mirror`'s `<Helper<'_>>::T` is `Trait::Gat<'_>`, but
+                // the `for<'ඞ> ඞSelf : Helper<'ඞ>` clause of this impl keeps
+                // the type system from seeing it, hence the detour through
+                // the `forwarding_trait()`, whose impl lacks such a clause.
+                let via = if tr.forwards_through_helpers(method) {
+                    forwarding_trait_name(mirror)
+                } else {
+                    tr.name.clone()
+                };
+                let mut call = tr.qualified_self(&via);
+                call.extend(parse("::"));
+                call.push(method.name.clone());
+                call.extend(turbofish(&method.params));
+                call.push(TT::Group(Group::new(Delimiter::Parenthesis, method.call_args().into_iter().collect())));
+                body.push(braces(unsafe_if(method.is_unsafe(), call)));
+            },
+        }
+    }
+    ret.push(braces(body));
+    ret
+}
+
+/// For the methods mentioning some `where Self : 'lt` GAT, a hidden trait,
+/// implemented by every `impl Trait`, with the very same methods, but for the
+/// GATs being spelled `<Self as MirrorඞGat<'lt>>::T`, through their
+/// [`helper()`] trait.
+///
+/// Its blanket impl can tell both types apart, and thus forward the calls
+/// to the `Trait` methods, as-is.
+fn forwarding_trait (
+    tr: &Trait,
+    mirror: &TT,
+) -> Vec<TT>
+{
+    let methods = tr.items.iter().filter_map(|item| match &item.kind {
+        | ItemKind::Fn(method) if tr.forwards_through_helpers(method) => Some((item, method)),
+        | _ => None,
+    });
+    let name = forwarding_trait_name(mirror);
+    let style = Style::Helper(mirror);
+    let mut decls = vec![];
+    let mut defs = vec![];
+    for (item, method) in methods {
+        let args = |named: bool| method.args.iter().enumerate().map(move |(i, (pat, ty))| {
+            let mut arg = if named { vec![arg_name(i)] } else { pat.clone() };
+            arg.extend(parse(":"));
+            arg.extend(tr.rewrite(ty.iter().cloned(), &tr.name, style));
+            arg
+        });
+        decls.extend(cfg_attrs(&item.attrs));
+        decls.extend(signature(tr, method, &tr.name, style, false, args(false)));
+        decls.extend(parse(";"));
+        defs.extend(cfg_attrs(&item.attrs));
+        defs.extend(signature(tr, method, &tr.name, style, true, args(true)));
+        let mut call = tr.qualified_self(&tr.name);
+        call.extend(parse("::"));
+        call.push(method.name.clone());
+        call.extend(turbofish(&method.params));
+        call.push(TT::Group(Group::new(Delimiter::Parenthesis, method.call_args().into_iter().collect())));
+        defs.push(braces(unsafe_if(method.is_unsafe(), call)));
+    }
+    if decls.is_empty() {
+        return vec![];
+    }
+    // #[doc(hidden)]
+    // trait $Forwarding<$decls> : $Trait<…> + Sized
+    // where
+    //     $where_clause
+    // {
+    //     $decls
+    // }
+    let mut ret = parse("#[doc(hidden)] trait");
+    ret.push(name.clone());
+    if tr.params.is_empty().not() {
+        ret.extend(angle_brackets(tr.decls(false)));
+    }
+    ret.extend(parse(":"));
+    ret.push(tr.name.clone());
+    ret.extend(tr.args());
+    ret.extend(parse("+ ::core::marker::Sized"));
+    ret.extend(tr.rewrite(tr.where_clause(), &tr.name, Style::Gat));
+    ret.push(braces(decls));
+    // impl<$decls ඞSelf : $Trait<…>> $Forwarding<…> for ඞSelf
+    // where
+    //     $where_clause
+    // {
+    //     $defs
+    // }
+    ret.extend(parse("impl"));
+    let mut params = tr.decls(false);
+    params.extend(parse(SELF));
+    params.extend(parse(":"));
+    params.push(tr.name.clone());
+    params.extend(tr.args());
+    ret.extend(angle_brackets(params));
+    ret.push(name);
+    ret.extend(tr.args());
+    ret.extend(parse("for"));
+    ret.extend(parse(SELF));
+    ret.extend(tr.rewrite(tr.where_clause(), &tr.name, Style::Gat));
+    ret.push(braces(defs));
+    ret
+}
+
+fn forwarding_trait_name (mirror: &TT)
+  -> TT
+{
+    parse(&format!("{mirror}ඞ")).pop().unwrap()
+}
+
+const UNFORWARDABLE_SELF: &str = "\
+    `#[ForLt_mirror]` cannot forward this `Self` type through `ViaForLt<Self>`: \
+    only `Self`, `&Self` and `&mut Self` parameters, and `Self` return types, are supported\
+";
+
+/// `impl<…, ඞSelf : Mirror<…>> Trait<…> for ViaForLt<ඞSelf>`
+fn reverse_impl (
+    tr: &Trait,
+    mirror: &TT,
+) -> Result<Vec<TT>>
+{
+    let mut ret = tr.unsafety.clone();
+    ret.extend(parse("impl"));
+    let mut params = tr.decls(false);
+    params.extend(parse(SELF));
+    params.extend(parse(":"));
+    params.push(mirror.clone());
+    params.extend(tr.args());
+    ret.extend(angle_brackets(params));
+    ret.push(tr.name.clone());
+    ret.extend(tr.args());
+    ret.extend(parse("for ::higher_kinded_types::ViaForLt"));
+    ret.extend(angle_brackets(parse(SELF)));
+    ret.extend(tr.where_clause());
+    if tr.supertraits.is_empty().not() {
+        ret.extend(parse("Self :"));
+        ret.extend(tr.supertraits.iter().cloned());
+        ret.extend(parse(","));
+    }
+    let qualified_self = tr.qualified_self(mirror);
+    let mut body = vec![];
+    for item in &tr.items {
+        body.extend(cfg_attrs(&item.attrs));
+        match &item.kind {
+            | ItemKind::Gat { name, lifetime, outlives_self } => {
+                // type Gat<'lt> = <<ඞSelf as Mirror>::Gat as ForLt>::Of<'lt>
+                // where
+                //     Self : 'lt,
+                // ;
+                body.extend(parse("type"));
+                body.push(name.clone());
+                body.extend(angle_brackets(lifetime.to_vec()));
+                body.extend(parse("="));
+                let mut for_lt = qualified_self.clone();
+                for_lt.extend(parse("::"));
+                for_lt.push(name.clone());
+                for_lt.extend(parse("as ::higher_kinded_types::ForLt"));
+                body.extend(angle_brackets(for_lt));
+                body.extend(parse("::Of"));
+                body.extend(angle_brackets(lifetime.to_vec()));
+                if *outlives_self {
+                    body.extend(parse("where Self :"));
+                    body.extend(lifetime.iter().cloned());
+                }
+                body.extend(parse(";"));
+            },
+            | ItemKind::Type { name, .. } => {
+                body.extend(parse("type"));
+                body.push(name.clone());
+                body.extend(parse("="));
+                body.extend(qualified_self.iter().cloned());
+                body.extend(parse("::"));
+                body.push(name.clone());
+                body.extend(parse(";"));
+            },
+            | ItemKind::Const { name, ty, .. } => {
+                body.extend(parse("const"));
+                body.push(name.clone());
+                body.extend(parse(":"));
+                body.extend(tr.rewrite(ty.iter().cloned(), &tr.name, Style::Gat));
+                body.extend(parse("="));
+                body.extend(qualified_self.iter().cloned());
+                body.extend(parse("::"));
+                body.push(name.clone());
+                body.extend(parse(";"));
+            },
+            | ItemKind::Fn(method) => {
+                let args = method.args.iter().enumerate().map(|(i, (_, ty))| {
+                    let mut arg = vec![arg_name(i)];
+                    arg.extend(parse(":"));
+                    arg.extend(tr.rewrite(ty.iter().cloned(), &tr.name, Style::Gat));
+                    arg
+                });
+                body.extend(signature(tr, method, &tr.name, Style::Gat, true, args));
+                let mut call_args = match &method.receiver {
+                    | None => vec![],
+                    | Some((_, Receiver::Value)) => parse("self.0,"),
+                    | Some((_, Receiver::Ref)) => parse("&self.0,"),
+                    | Some((_, Receiver::RefMut)) => parse("&mut self.0,"),
+                };
+                // `Self` (that is, `ViaForLt<ඞSelf>`) arguments are unwrapped.
+                for (i, (_, ty)) in method.args.iter().enumerate() {
+                    if let Some(borrow) = self_by(ty) {
+                        call_args.extend(borrow);
+                        call_args.push(arg_name(i));
+                        call_args.extend(parse(".0"));
+                    } else if let Some(span) = bare_self(ty) {
+                        return bail(span, UNFORWARDABLE_SELF);
+                    } else {
+                        call_args.push(arg_name(i));
+                    }
+                    call_args.extend(parse(","));
+                }
+                let mut call = qualified_self.clone();
+                call.extend(parse("::"));
+                call.push(method.name.clone());
+                call.extend(turbofish(&method.params));
+                call.push(TT::Group(Group::new(Delimiter::Parenthesis, call_args.into_iter().collect())));
+                // And a `Self` return value, wrapped.
+                match &method.ret[..] {
+                    | [_, _, this] if is_ident(this, "Self") => {
+                        let mut wrapped = parse("::higher_kinded_types::ViaForLt");
+                        wrapped.push(TT::Group(Group::new(Delimiter::Parenthesis, call.into_iter().collect())));
+                        call = wrapped;
+                    },
+                    | ret => if let Some(span) = bare_self(ret) {
+                        return bail(span, UNFORWARDABLE_SELF);
+                    },
+                }
+                body.push(braces(unsafe_if(method.is_unsafe(), call)));
+            },
+        }
+    }
+    ret.push(braces(body));
+    Ok(ret)
+}
+
+impl Method {
+    fn is_unsafe (&self)
+      -> bool
+    {
+        self.qualifiers.iter().any(|tt| is_ident(tt, "unsafe"))
+    }
+
+    /// `self, arg_0, arg_1,`
+    fn call_args (&self)
+      -> Vec<TT>
+    {
+        let mut ret = vec![];
+        if self.receiver.is_some() {
+            ret.extend(parse("self,"));
+        }
+        for i in 0 .. self.args.len() {
+            ret.push(arg_name(i));
+            ret.extend(parse(","));
+        }
+        ret
+    }
+}
+
+/// `unsafe fn name<…>(self, args…) -> Ret where …`, for the given `trait_name`
+/// and `style` (and for a forwarding impl, if `forwarding`).
+fn signature (
+    tr: &Trait,
+    method: &Method,
+    trait_name: &TT,
+    style: Style,
+    forwarding: bool,
+    args: impl Iterator<Item = Vec<TT>>,
+) -> Vec<TT>
+{
+    let mut ret = method.qualifiers.clone();
+    ret.extend(parse("fn"));
+    ret.push(method.name.clone());
+    if method.params.is_empty().not() {
+        let mut params = vec![];
+        for param in &method.params {
+            params.extend(tr.rewrite(param.decl.iter().cloned(), trait_name, style));
+            params.extend(parse(","));
+        }
+        ret.extend(angle_brackets(params));
+    }
+    let mut params = vec![];
+    if let Some((receiver, _)) = &method.receiver {
+        match &receiver[..] {
+            // (no `mut self` in the impls, wherein `self` is just forwarded)
+            | [mut_, rest @ ..] if forwarding && is_ident(mut_, "mut") => params.extend(rest.iter().cloned()),
+            | _ => params.extend(receiver.iter().cloned()),
+        }
+        params.extend(parse(","));
+    }
+    for arg in args {
+        params.extend(arg);
+        params.extend(parse(","));
+    }
+    ret.push(TT::Group(Group::new(Delimiter::Parenthesis, params.into_iter().collect())));
+    ret.extend(tr.rewrite(method.ret.iter().cloned(), trait_name, style));
+    ret.extend(tr.rewrite(method.where_clause.iter().cloned(), trait_name, style));
+    ret
+}
+
+/// `::<T, N>`, for the type and const generic parameters (the lifetime ones
+/// may be late-bound, and are inferred anyways).
+fn turbofish (params: &[Param])
+  -> Vec<TT>
+{
+    let mut args = vec![];
+    for param in params {
+        if let ParamKind::Named(name) = &param.kind {
+            args.push(name.clone());
+            args.extend(parse(","));
+        }
+    }
+    if args.is_empty() {
+        return vec![];
+    }
+    let mut ret = parse("::");
+    ret.extend(angle_brackets(args));
+    ret
+}
+
+/// For a `Self`, `&Self` or `&mut Self` type, the borrow with which to
+/// pass its `.0` along.
+fn self_by (ty: &[TT])
+  -> Option<Vec<TT>>
+{
+    let (this, borrow) = ty.split_last()?;
+    if is_ident(this, "Self").not() {
+        return None;
+    }
+    match borrow {
+        | [] => Some(vec![]),
+        | [amp, rest @ ..] if amp.to_string() == "&" => {
+            let rest = match rest {
+                | [apostrophe, _, rest @ ..] if apostrophe.to_string() == "'" => rest,
+                | _ => rest,
+            };
+            match rest {
+                | [] => Some(parse("&")),
+                | [mut_] if is_ident(mut_, "mut") => Some(parse("&mut")),
+                | _ => None,
+            }
+        },
+        | _ => None,
+    }
+}
+
+/// The span of some `Self` type (other than a `Self::Assoc` path) among
+/// `tokens`, if any.
+fn bare_self (tokens: &[TT])
+  -> Option<Span>
+{
+    tokens.iter().enumerate().find_map(|(i, tt)| match tt {
+        | TT::Group(g) => bare_self(&g.stream().into_iter().collect::<Vec<_>>()),
+        | _ if is_ident(tt, "Self") && matches!(
+            tokens.get(i + 1),
+            Some(TT::Punct(p)) if p.as_char() == ':' && p.spacing() == Spacing::Joint,
+        ).not() => Some(tt.span()),
+        | _ => None,
+    })
+}
+
+fn unsafe_if (unsafety: bool, expr: Vec<TT>)
+  -> Vec<TT>
+{
+    if unsafety.not() {
+        return expr;
+    }
+    let mut ret = parse("unsafe");
+    ret.push(braces(expr));
+    ret
+}
+
+/// The (hygienic) names of the forwarded parameters.
+fn arg_name (i: usize)
+  -> TT
+{
+    TT::Ident(::proc_macro::Ident::new(&format!("arg_{i}"), Span::mixed_site()))
+}
+
+/// The `#[cfg(…)]` attributes among `attrs`.
+fn cfg_attrs (attrs: &[TT])
+  -> Vec<TT>
+{
+    attrs.chunks(2).filter(|attr| matches!(
+        &attr[1],
+        TT::Group(g) if matches!(g.stream().into_iter().next(), Some(tt) if is_ident(&tt, "cfg"))
+    )).flatten().cloned().collect()
+}
+
+fn braces (tokens: Vec<TT>)
+  -> TT
+{
+    TT::Group(Group::new(Delimiter::Brace, tokens.into_iter().collect()))
+}
@@ -0,0 +1,175 @@
+//! Parsing and emission helpers shared by the derives and attributes.
+
+use super::*;
+
+/// A generic parameter (of the deriving type, or of the trait).
+pub(crate)
+struct Param {
+    /// The `#[attrs]` and the parameter itself, minus any `= Default`.
+    pub(crate) decl: Vec<TT>,
+    /// The `= Default`, if any.
+    pub(crate) default: Vec<TT>,
+    pub(crate) kind: ParamKind,
+}
+
+pub(crate)
+enum ParamKind {
+    Lifetime(Lifetime),
+    /// A type or `const` parameter.
+    Named(TT),
+}
+
+/// Parses the `'a : 'b, T : Bound = Default, const N : usize>` generic
+/// parameters, up to and including the closing `>`.
+pub(crate)
+fn generic_params (
+    input: &mut Cursor,
+) -> Result<Vec<Param>>
+{
+    let mut params = vec![];
+    loop {
+        if input.is_punct(0, '>') {
+            input.next();
+            return Ok(params);
+        }
+        let mut decl = vec![];
+        while input.is_punct(0, '#') {
+            decl.extend(input.next());
+            decl.extend(input.next()); // `[…]`
+        }
+        let kind = if input.is_lifetime(0) {
+            ParamKind::Lifetime([input.peek(0).unwrap().clone(), input.peek(1).unwrap().clone()])
+        } else {
+            if input.is_ident(0, "const") {
+                decl.extend(input.next());
+            }
+            match input.peek(0) {
+                | Some(name @ TT::Ident(_)) => ParamKind::Named(name.clone()),
+                | _ => return bail(input.span(), "expected a generic parameter"),
+            }
+        };
+        // The rest of the parameter, up until the `,` or `>` at depth 0,
+        // with the `= Default` set aside.
+        let mut depth = 0_usize;
+        let mut default = vec![];
+        loop {
+            let Some(tt) = input.peek(0) else {
+                return bail(input.end_span(), "expected `>`");
+            };
+            match tt {
+                // (the `>` of a `->`)
+                | TT::Punct(p) if p.as_char() == '>' && prev_is_dash(input) => {},
+                | TT::Punct(p) if depth == 0 && matches!(p.as_char(), ',' | '>') => break,
+                | TT::Punct(p) if p.as_char() == '<' => depth += 1,
+                | TT::Punct(p) if p.as_char() == '>' => depth -= 1,
+                | TT::Punct(p) if depth == 0 && p.as_char() == '=' && default.is_empty() => {
+                    default.push(input.next().unwrap());
+                    continue;
+                },
+                | _ => {},
+            }
+            let tt = input.next().unwrap();
+            if default.is_empty() {
+                decl.push(tt);
+            } else {
+                default.push(tt);
+            }
+        }
+        if input.is_punct(0, ',') {
+            input.next();
+        }
+        params.push(Param { decl, default, kind });
+    }
+}
+
+pub(crate)
+fn prev_is_dash (input: &Cursor)
+  -> bool
+{
+    matches!(
+        input.tts[.. input.pos].last(),
+        Some(TT::Punct(p)) if p.as_char() == '-' && p.spacing() == Spacing::Joint
+    )
+}
+
+pub(crate)
+fn angle_brackets (args: Vec<TT>)
+  -> Vec<TT>
+{
+    let mut ret = parse("<");
+    ret.extend(args);
+    ret.extend(parse(">"));
+    ret
+}
+
+pub(crate)
+fn parse (code: &str)
+  -> Vec<TT>
+{
+    code.parse::<TokenStream>().unwrap().into_iter().collect()
+}
+
+/// Splits a comma-separated list (such as a function's parameters), minding
+/// the `<…>` (but not the `->`) within each element.
+pub(crate)
+fn split_commas (tokens: TokenStream)
+  -> Vec<Vec<TT>>
+{
+    let mut ret = vec![];
+    let mut current = vec![];
+    let mut depth = 0_usize;
+    let input = &mut Cursor::new(tokens);
+    while let Some(tt) = input.peek(0) {
+        match tt {
+            | TT::Punct(p) if p.as_char() == '>' && prev_is_dash(input) => {},
+            | TT::Punct(p) if depth == 0 && p.as_char() == ',' => {
+                input.next();
+                ret.push(::core::mem::take(&mut current));
+                continue;
+            },
+            | TT::Punct(p) if p.as_char() == '<' => depth += 1,
+            | TT::Punct(p) if p.as_char() == '>' => depth = depth.saturating_sub(1),
+            | _ => {},
+        }
+        current.extend(input.next());
+    }
+    if current.is_empty().not() {
+        ret.push(current);
+    }
+    ret
+}
+
+/// The position of the first `c` punctuation among `tokens`, outside of any
+/// `<…>`, and which is not part of a multi-character one (such as `::`).
+pub(crate)
+fn top_level_position (tokens: &[TT], c: char)
+  -> Option<usize>
+{
+    let mut depth = 0_usize;
+    let mut prev: Option<&::proc_macro::Punct> = None;
+    for (i, tt) in tokens.iter().enumerate() {
+        let TT::Punct(p) = tt else {
+            prev = None;
+            continue;
+        };
+        let prev_joint = prev.map_or(false, |prev| prev.spacing() == Spacing::Joint);
+        match p.as_char() {
+            | '>' if prev_joint && prev.map_or(false, |prev| prev.as_char() == '-') => {},
+            | '<' => depth += 1,
+            | '>' => depth = depth.saturating_sub(1),
+            | ch if ch == c && depth == 0 && prev_joint.not() && p.spacing() == Spacing::Alone => {
+                return Some(i);
+            },
+            | _ => {},
+        }
+        prev = Some(p);
+    }
+    None
+}
+
+pub(crate)
+fn is_ident (tt: &TT, name: &str)
+  -> bool
+{
+    matches!(tt, TT::Ident(i) if i.to_string() == name)
+}
@@ -0,0 +1,200 @@
+//! Readable names for the HKT types, _e.g._, `for<'r> &'r str`, rather than
+//! the [`type_name`] of their internals.
+//!
+//! There is one such function per arity: [`hkt_type_name()`] for the
+//! [`ForLt`][ForLt] types, [`hkt_type_name_for_ty()`] for the
+//! [`ForTy`] ones, and so on.
+//!
+//! [ForLt]: trait@ForLt
+//!
+//! The nominal types defined by
+//! [`new_For_type!`][crate::extra_arities::new_For_type!] record their source
+//! (by means of [`stringify!`]) when expanded, which is thus rendered as
+//! written, behind a `for<…>` binder naming its parameters, whatever the
+//! compiler version (the generic parameters of the nominal type itself, if
+//! any, are thus rendered by name as well).
+//!
+//! The anonymous types produced by [`ForLt!`][crate::ForLt!] or
+//! [`For!`][crate::extra_arities::For!] have nowhere to record it, though: a
+//! macro in type position cannot emit the items that would, and baking the
+//! source into the type itself would make, say, `ForLt!(&str)` and
+//! `ForLt!(<'a> = &'a str)` distinct types. Their name thus falls back to the
+//! [`type_name`] of their `Of<'static, …>`, which is a best-effort
+//! description, lifetimes and binder omitted (`&str`), whose exact output may
+//! change across compiler versions. Wrap such a type in a
+//! `new_For_type! { type Name = For!(#![nominal] <'r> = …); }` for it to be
+//! named as written.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ::higher_kinded_types::{
+//!     extra_arities::*,
+//!     hkt_type_name,
+//!     type_name::{hkt_type_name_for_lt_and_ty, hkt_type_name_for_ty},
+//! };
+//!
+//! new_For_type! {
+//!     type StrRef = For!(#![nominal] <'r> = &'r str);
+//!     type VecFor = For!(<T> = Vec<T>);
+//!     type MapRef = For!(<'r, K> = &'r ::std::collections::HashMap<K, u8>);
+//! }
+//!
+//! assert_eq!(hkt_type_name::<StrRef>().to_string(), "for<'r> &'r str");
+//! assert_eq!(hkt_type_name_for_ty::<VecFor>().to_string(), "for<T> Vec<T>");
+//! assert_eq!(
+//!     hkt_type_name_for_lt_and_ty::<MapRef>().to_string(),
+//!     "for<'r, K> &'r ::std::collections::HashMap<K, u8>",
+//! );
+//!
+//! // Anonymous types: best-effort.
+//! assert_eq!(hkt_type_name::<For!(&str)>().to_string(), "&str");
+//! ```
+
+use {
+    ::core::{
+        any::type_name,
+        fmt::{self, Write as _},
+    },
+    crate::{
+        extra_arities::*,
+        ForLt,
+    },
+};
+
+/// Placeholder types, fed to the type parameters of the HKT types so as to
+/// render them, by name, in the resulting type.
+mod placeholders {
+    pub enum T {}
+    pub enum U {}
+    pub enum V {}
+    pub enum W {}
+}
+use placeholders::*;
+
+/// A readable name for the given <code>T : [ForLt]</code> type, such as
+/// `for<'r> &'r str`.
+///
+/// [ForLt]: trait@ForLt
+///
+/// See the [module docs][self] for more info, and the caveats.
+///
+/// ```rust
+/// use ::higher_kinded_types::{extra_arities::*, hkt_type_name, ForFixed};
+///
+/// new_For_type! {
+///     type Token = For!(#![nominal] <'src> = (&'src str, usize));
+/// }
+///
+/// assert_eq!(hkt_type_name::<Token>().to_string(), "for<'src> (&'src str, usize)");
+///
+/// // Anonymous types are named after their `Of<'static>`.
+/// assert_eq!(hkt_type_name::<ForFixed<Vec<u8>>>().to_string(), "Vec<u8>");
+/// ```
+pub
+fn hkt_type_name<T : ForLt>()
+  -> impl fmt::Display + fmt::Debug
+{
+    HktTypeName {
+        recorded: T::ඞtype_name(),
+        of: type_name::<T::Of<'static>>(),
+    }
+}
+
+/// Generates the [`hkt_type_name()`] counterparts of the other arities, by
+/// feeding `'static` and the placeholder types to their parameters (for the
+/// fallback).
+macro_rules! hkt_type_names {(
+    $(
+        $fn_name:ident($Trait:ident<$($arg:tt),*>);
+    )*
+) => (
+    $(
+        #[doc = ::core::concat!(
+            "A readable name for the given <code>F : [", ::core::stringify!($Trait), "]</code> ",
+            "type.",
+        )]
+        ///
+        /// See the [module docs][self] for more info, and the caveats.
+        pub
+        fn $fn_name<F : $Trait>()
+          -> impl fmt::Display + fmt::Debug
+        {
+            HktTypeName {
+                recorded: F::ඞtype_name(),
+                of: type_name::<F::Of<$($arg),*>>(),
+            }
+        }
+    )*
+)}
+
+hkt_type_names! {
+    hkt_type_name_for_lt_unsized(ForLtUnsized<'static>);
+    hkt_type_name_for_ty(ForTy<T>);
+    hkt_type_name_for_unsized_ty(ForUnsizedTy<T>);
+    hkt_type_name_for_lt_and_lt(ForLtAndLt<'static, 'static>);
+    hkt_type_name_for_lt_and_lt_and_lt(ForLtAndLtAndLt<'static, 'static, 'static>);
+    hkt_type_name_for_lt_and_lt_and_lt_and_lt(
+        ForLtAndLtAndLtAndLt<'static, 'static, 'static, 'static>
+    );
+    hkt_type_name_for_lt_and_ty(ForLtAndTy<'static, T>);
+    hkt_type_name_for_lt_and_unsized_ty(ForLtAndUnsizedTy<'static, T>);
+    hkt_type_name_for_ty_and_ty(ForTyAndTy<T, U>);
+    hkt_type_name_for_ty_and_ty_and_ty(ForTyAndTyAndTy<T, U, V>);
+    hkt_type_name_for_ty_and_ty_and_ty_and_ty(ForTyAndTyAndTyAndTy<T, U, V, W>);
+    hkt_type_name_for_lt_and_ty_and_ty(ForLtAndTyAndTy<'static, T, U>);
+    hkt_type_name_for_lt_and_ty_and_ty_and_ty(ForLtAndTyAndTyAndTy<'static, T, U, V>);
+    hkt_type_name_for_lt_and_lt_and_ty(ForLtAndLtAndTy<'static, 'static, T>);
+    hkt_type_name_for_lt_and_lt_and_ty_and_ty(ForLtAndLtAndTyAndTy<'static, 'static, T, U>);
+    hkt_type_name_for_lt_and_lt_and_lt_and_ty(ForLtAndLtAndLtAndTy<'static, 'static, 'static, T>);
+}
+
+struct HktTypeName {
+    /// The `for<…> …` source of a nominal type, as recorded by
+    /// `new_For_type!`.
+    recorded: Option<&'static str>,
+    /// The [`type_name`] of its `Of<…>`, fed `'static` and the placeholder
+    /// types, as a fallback (for the anonymous types).
+    of: &'static str,
+}
+
+impl fmt::Display for HktTypeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.recorded {
+            | Some(recorded) => f.write_str(recorded),
+            | None => write_unqualified(f, self.of),
+        }
+    }
+}
+
+impl fmt::Debug for HktTypeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Writes `name` with its paths stripped (`alloc::vec::Vec<u8>` becomes
+/// `Vec<u8>`).
+fn write_unqualified(
+    f: &mut fmt::Formatter<'_>,
+    mut name: &str,
+) -> fmt::Result
+{
+    let is_ident = |c: char| c == '_' || c.is_alphanumeric();
+    while let Some(c) = name.chars().next() {
+        let len = if is_ident(c) {
+            let len = name.find(|c| !is_ident(c)).unwrap_or(name.len());
+            if name[len ..].starts_with("::") {
+                len + "::".len()
+            } else {
+                f.write_str(&name[.. len])?;
+                len
+            }
+        } else {
+            f.write_char(c)?;
+            c.len_utf8()
+        };
+        name = &name[len ..];
+    }
+    Ok(())
+}
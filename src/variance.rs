@@ -0,0 +1,166 @@
+//! Niche and advanced, you can ignore this to begin with.
+//! Shrinking whole containers of [`CovariantForLt`] items at once.
+//!
+//! [`CovariantForLt::covariant_cast()`] only shrinks a single `T::Of<'long>`,
+//! so that, for a `Vec<T::Of<'long>>`, one would otherwise end up spelling out
+//! the `.into_iter().map(T::covariant_cast).collect()` dance.
+//!
+//! These helpers are built on top of said [`CovariantForLt::covariant_cast()`],
+//! and thus involve no `unsafe` whatsoever, but they may have to rebuild the
+//! container (see each function for the details).
+//!
+//! Containers which cannot be so rebuilt, such as a `&[T::Of<'long>]` slice or
+//! an arbitrary `Wrapper::Of<T::Of<'long>>`, cannot be shrunk, since a generic
+//! `T::Of<'_>` is invariant: the concrete types have to be involved, _e.g._,
+//! through a [`new_For_type!`]-defined family which would feature them.
+//!
+//! [`new_For_type!`]: crate::extra_arities::new_For_type!
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ::higher_kinded_types::{
+//!     extra_arities::{new_For_type, For, CovariantForLt},
+//!     variance,
+//! };
+//!
+//! new_For_type! {
+//!     type StrRef = For!(#![covariant]<'r> = &'r str);
+//! }
+//!
+//! fn first_or_local<'long, T : CovariantForLt>(
+//!     first: Option<T::Of<'long>>,
+//!     local: impl FnOnce(&str) -> T::Of<'_>,
+//! ) -> bool
+//! where
+//!     for<'r> T::Of<'r> : PartialEq,
+//! {
+//!     let s = String::from("b");
+//!     let local = local(&s);
+//!     variance::covariant_cast_option::<T>(first).map_or(false, |it| it == local)
+//! }
+//!
+//! assert!(first_or_local::<StrRef>(Some("b"), |s| s));
+//! ```
+
+#[cfg(feature = "std")]
+use ::std::{
+    boxed::Box,
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+    vec::Vec,
+};
+use crate::{
+    extra_arities::{
+        CovariantForLt,
+    },
+};
+
+/// Shrinks `Option<T::Of<'humongous>>` into `Option<T::Of<'smol>>`.
+#[inline]
+pub
+fn covariant_cast_option<'smol, 'humongous : 'smol, T : CovariantForLt>(
+    it: Option<T::Of<'humongous>>,
+) -> Option<T::Of<'smol>>
+{
+    it.map(T::covariant_cast)
+}
+
+/// Shrinks `Vec<T::Of<'humongous>>` into `Vec<T::Of<'smol>>`.
+///
+/// Note that this is a mere `.into_iter().map(…).collect()`: no reuse of the
+/// allocation is guaranteed, so this may rebuild the whole `Vec`.
+///
+/// ```rust
+/// use ::higher_kinded_types::{
+///     extra_arities::{new_For_type, For, CovariantForLt},
+///     variance::covariant_cast_vec,
+/// };
+///
+/// new_For_type! {
+///     type StrRef = For!(#![covariant]<'r> = &'r str);
+/// }
+///
+/// fn with_local<'long, T : CovariantForLt>(
+///     items: Vec<T::Of<'long>>,
+///     local: impl FnOnce(&str) -> T::Of<'_>,
+/// ) -> usize
+/// {
+///     let s = String::from("local");
+///     let mut items = covariant_cast_vec::<T>(items);
+///     items.push(local(&s));
+///     items.len()
+/// }
+///
+/// let n = with_local::<StrRef>(vec!["a", "b"], |s| s);
+/// assert_eq!(n, 3);
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "docs-rs", doc(cfg(feature = "std")))]
+#[inline]
+pub
+fn covariant_cast_vec<'smol, 'humongous : 'smol, T : CovariantForLt>(
+    it: Vec<T::Of<'humongous>>,
+) -> Vec<T::Of<'smol>>
+{
+    it.into_iter().map(T::covariant_cast).collect()
+}
+
+/// Shrinks `Box<T::Of<'humongous>>` into `Box<T::Of<'smol>>`.
+///
+/// Note that this moves the item into a new `Box`.
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "docs-rs", doc(cfg(feature = "std")))]
+#[inline]
+#[allow(clippy::boxed_local)] // the `Box` is the very thing being cast.
+pub
+fn covariant_cast_box<'smol, 'humongous : 'smol, T : CovariantForLt>(
+    it: Box<T::Of<'humongous>>,
+) -> Box<T::Of<'smol>>
+{
+    Box::new(T::covariant_cast(*it))
+}
+
+/// Shrinks the values of a `HashMap<K, T::Of<'humongous>>` into
+/// `T::Of<'smol>`s.
+///
+/// Note that this rebuilds the map, rehashing every key, albeit with (a clone
+/// of) the original hasher.
+///
+/// ```rust
+/// use {
+///     ::std::collections::HashMap,
+///     ::higher_kinded_types::{
+///         extra_arities::{new_For_type, For},
+///         variance::covariant_cast_hash_map,
+///     },
+/// };
+///
+/// new_For_type! {
+///     type StrRef = For!(#![covariant]<'r> = &'r str);
+/// }
+///
+/// let local = String::from("local");
+/// let map: HashMap<u8, &'static str> = [(0, "static")].into_iter().collect();
+/// let mut map = covariant_cast_hash_map::<_, StrRef, _>(map);
+/// map.insert(1, &local);
+/// assert_eq!(map[&0], "static");
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "docs-rs", doc(cfg(feature = "std")))]
+#[inline]
+pub
+fn covariant_cast_hash_map<'smol, 'humongous : 'smol, K, T : CovariantForLt, S>(
+    it: HashMap<K, T::Of<'humongous>, S>,
+) -> HashMap<K, T::Of<'smol>, S>
+where
+    K : Eq + Hash,
+    S : BuildHasher + Clone,
+{
+    let mut map = HashMap::with_capacity_and_hasher(
+        it.len(),
+        it.hasher().clone(),
+    );
+    map.extend(it.into_iter().map(|(k, v)| (k, T::covariant_cast(v))));
+    map
+}